@@ -0,0 +1,152 @@
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+use assembler::listing;
+
+use cpu::{Cpu, Step};
+use source_map::SourceMap;
+
+#[derive(Default)]
+struct LineCoverage {
+    // Most executions of any instruction generated from the line
+    hits: u64,
+    // (ROM address, [not taken, taken]) of conditional jumps on the line
+    branches: Vec<(u16, Option<[u64; 2]>)>,
+}
+
+type FileCoverage = BTreeMap<usize, LineCoverage>;
+
+/// Counts executions of each ROM address and outcomes of each conditional
+/// jump, reported back against the sources the program was built from.
+pub struct Coverage {
+    hits: Vec<u64>,
+    conditional: Vec<bool>,
+    branches: Vec<[u64; 2]>,
+}
+
+impl Coverage {
+    pub fn new(rom: &[u16]) -> Self {
+        Coverage {
+            hits: vec![0; rom.len()],
+            conditional: rom.iter().map(|&instruction| is_conditional_jump(instruction)).collect(),
+            branches: vec![[0; 2]; rom.len()],
+        }
+    }
+
+    pub fn record(&mut self, step: &Step, cpu: &Cpu) {
+        let pc = step.pc as usize;
+        if pc >= self.hits.len() {
+            return;
+        }
+
+        self.hits[pc] += 1;
+        if self.conditional[pc] {
+            let taken = cpu.pc != step.pc.wrapping_add(1);
+            self.branches[pc][taken as usize] += 1;
+        }
+    }
+
+    pub fn summary(&self) -> String {
+        let executed = self.hits.iter().filter(|&&hits| hits > 0).count();
+        format!("Executed {} of {} ROM addresses ({:.1}%).",
+                executed,
+                self.hits.len(),
+                100.0 * executed as f64 / self.hits.len().max(1) as f64)
+    }
+
+    /// Writes an lcov tracefile for the program at `program_path`. Each ROM
+    /// address is attributed to its .asm line, and further to .vm and .jack
+    /// lines wherever the translator and compiler left source maps behind.
+    pub fn write_lcov<W: Write>(&self, out: &mut W, program_path: &Path) -> io::Result<()> {
+        let mut buffer = String::new();
+        File::open(program_path)?.read_to_string(&mut buffer)?;
+
+        // Each line of a .hack file is one ROM address
+        let program_lines = if program_path.extension().is_some_and(|ext| ext == "asm") {
+            listing(&buffer)
+        } else {
+            (1..=self.hits.len()).collect()
+        };
+
+        let directory = program_path.parent().unwrap_or_else(|| Path::new(""));
+        let asm_map = SourceMap::load(program_path);
+        let mut vm_maps: BTreeMap<PathBuf, Option<SourceMap>> = BTreeMap::new();
+        let mut files: BTreeMap<PathBuf, FileCoverage> = BTreeMap::new();
+
+        for (address, &line) in program_lines.iter().enumerate().take(self.hits.len()) {
+            let mut locations = vec![(program_path.to_path_buf(), line)];
+
+            if let Some((vm_file, vm_line)) = asm_map.as_ref().and_then(|map| map.lookup(line)) {
+                let vm_path = directory.join(vm_file);
+                let vm_map = vm_maps.entry(vm_path.clone()).or_insert_with(|| SourceMap::load(&vm_path));
+                if let Some((jack_file, jack_line)) = vm_map.as_ref().and_then(|map| map.lookup(vm_line)) {
+                    locations.push((directory.join(jack_file), jack_line));
+                }
+                locations.push((vm_path, vm_line));
+            }
+
+            for (path, line) in locations {
+                self.attribute(files.entry(path).or_default().entry(line).or_default(), address);
+            }
+        }
+
+        for (path, lines) in &files {
+            write_record(out, path, lines)?;
+        }
+        Ok(())
+    }
+
+    fn attribute(&self, line: &mut LineCoverage, address: usize) {
+        line.hits = line.hits.max(self.hits[address]);
+        if self.conditional[address] {
+            let outcomes = if self.hits[address] > 0 {
+                Some(self.branches[address])
+            } else {
+                None
+            };
+            line.branches.push((address as u16, outcomes));
+        }
+    }
+}
+
+// Any jump other than `;JMP`
+fn is_conditional_jump(instruction: u16) -> bool {
+    let jump = instruction & 0x7;
+    instruction & 0x8000 != 0 && jump != 0 && jump != 0x7
+}
+
+fn write_record<W: Write>(out: &mut W, path: &Path, lines: &FileCoverage) -> io::Result<()> {
+    writeln!(out, "TN:")?;
+    writeln!(out, "SF:{}", path.display())?;
+
+    let mut branches_found = 0;
+    let mut branches_hit = 0;
+    for (&line, coverage) in lines {
+        for &(address, outcomes) in &coverage.branches {
+            for branch in 0..2 {
+                branches_found += 1;
+                match outcomes {
+                    Some(outcomes) => {
+                        if outcomes[branch] > 0 {
+                            branches_hit += 1;
+                        }
+                        writeln!(out, "BRDA:{},{},{},{}", line, address, branch, outcomes[branch])?;
+                    }
+                    None => writeln!(out, "BRDA:{},{},{},-", line, address, branch)?,
+                }
+            }
+        }
+    }
+    writeln!(out, "BRF:{}", branches_found)?;
+    writeln!(out, "BRH:{}", branches_hit)?;
+
+    for (&line, coverage) in lines {
+        writeln!(out, "DA:{},{}", line, coverage.hits)?;
+    }
+    writeln!(out, "LF:{}", lines.len())?;
+    writeln!(out, "LH:{}", lines.values().filter(|coverage| coverage.hits > 0).count())?;
+    writeln!(out, "end_of_record")
+}
@@ -0,0 +1,119 @@
+pub const RAM_SIZE: usize = 32768;
+pub const SCREEN: u16 = 16384;
+pub const KBD: u16 = 24576;
+
+// `0;JMP`
+const JMP: u16 = 0b1110_1010_1000_0111;
+
+pub struct Cpu {
+    pub rom: Vec<u16>,
+    pub ram: Vec<u16>,
+    pub a: u16,
+    pub d: u16,
+    pub pc: u16,
+    pub cycles: u64,
+}
+
+/// What a single executed instruction did.
+pub struct Step {
+    pub pc: u16,
+    pub instruction: u16,
+    pub read: Option<u16>,
+    pub write: Option<MemoryWrite>,
+}
+
+#[derive(Clone, Copy)]
+pub struct MemoryWrite {
+    pub address: u16,
+    pub value: u16,
+    pub previous: u16,
+}
+
+impl Cpu {
+    pub fn new(rom: Vec<u16>) -> Self {
+        Cpu {
+            rom,
+            ram: vec![0; RAM_SIZE],
+            a: 0,
+            d: 0,
+            pc: 0,
+            cycles: 0,
+        }
+    }
+
+    /// A program halts by spinning on `(END) @END 0;JMP`, so we are done
+    /// once PC sits on an `@n` at address n followed by `0;JMP`.
+    pub fn halted(&self) -> bool {
+        let pc = self.pc as usize;
+        pc + 1 < self.rom.len() && self.rom[pc] == self.pc && self.rom[pc + 1] == JMP
+    }
+
+    pub fn fetch(&self) -> u16 {
+        // Unused ROM reads as zero, i.e. `@0`
+        self.rom.get(self.pc as usize).cloned().unwrap_or(0)
+    }
+
+    pub fn step(&mut self) -> Step {
+        let pc = self.pc;
+        let instruction = self.fetch();
+        let mut read = None;
+        let mut write = None;
+
+        self.cycles += 1;
+        self.pc = pc.wrapping_add(1);
+
+        if instruction & 0x8000 == 0 {
+            self.a = instruction;
+        } else {
+            let address = self.a & 0x7FFF;
+            let y = if instruction & 0x1000 != 0 {
+                read = Some(address);
+                self.ram[address as usize]
+            } else {
+                self.a
+            };
+            let out = alu(self.d, y, (instruction >> 6) & 0x3F);
+
+            if instruction & 0x08 != 0 {
+                write = Some(MemoryWrite {
+                    address,
+                    value: out,
+                    previous: self.ram[address as usize],
+                });
+                self.ram[address as usize] = out;
+            }
+            if instruction & 0x10 != 0 {
+                self.d = out;
+            }
+            let jump_target = self.a;
+            if instruction & 0x20 != 0 {
+                self.a = out;
+            }
+
+            let out = out as i16;
+            let jump = (instruction & 0x4 != 0 && out < 0) ||
+                       (instruction & 0x2 != 0 && out == 0) ||
+                       (instruction & 0x1 != 0 && out > 0);
+            if jump {
+                self.pc = jump_target;
+            }
+        }
+
+        Step {
+            pc,
+            instruction,
+            read,
+            write,
+        }
+    }
+}
+
+fn alu(x: u16, y: u16, control: u16) -> u16 {
+    let x = if control & 0x20 != 0 { 0 } else { x };
+    let x = if control & 0x10 != 0 { !x } else { x };
+    let y = if control & 0x08 != 0 { 0 } else { y };
+    let y = if control & 0x04 != 0 { !y } else { y };
+    let out = if control & 0x02 != 0 { x.wrapping_add(y) } else { x & y };
+
+    if control & 0x01 != 0 { !out } else { out }
+}
@@ -0,0 +1,213 @@
+use std::fs::File;
+use std::io;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+
+use assembler::predefined_symbol_table;
+
+use cpu::{Cpu, RAM_SIZE};
+use disassembler::disassemble;
+use history::History;
+use snapshot;
+use watch::{Access, Breaks, Watchpoint};
+
+const HELP: &str = "\
+step [N]          execute N instructions (s)
+back [N]          undo the last N instructions (b)
+run [N]           run until halt, or at most N instructions (r)
+last-write ADDR   find the most recent write to RAM[ADDR] (w)
+ram ADDR [N]      print N words of RAM starting at ADDR (x)
+watch LO[-HI]     stop on writes to RAM[LO..=HI]
+rwatch LO[-HI]    stop on reads of RAM[LO..=HI]
+awatch LO[-HI]    stop on reads or writes of RAM[LO..=HI]
+unwatch           remove all watchpoints
+regs              print PC, A, D and the next instruction
+save FILE         write a snapshot of the machine
+load FILE         restore a snapshot, forgetting history
+quit              leave the debugger (q)";
+
+pub struct Debugger<'a> {
+    cpu: Cpu,
+    history: History,
+    breaks: Breaks,
+    labels: &'a [(String, u16)],
+}
+
+impl<'a> Debugger<'a> {
+    pub fn new(cpu: Cpu, history: History, breaks: Breaks, labels: &'a [(String, u16)]) -> Self {
+        Debugger {
+            cpu,
+            history,
+            breaks,
+            labels,
+        }
+    }
+
+    pub fn run<R: BufRead, W: Write>(&mut self, input: R, out: &mut W) -> io::Result<()> {
+        self.print_state(out)?;
+        write!(out, "> ")?;
+        out.flush()?;
+
+        for line in input.lines() {
+            let line = line?;
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            if tokens.is_empty() {
+                write!(out, "> ")?;
+                out.flush()?;
+                continue;
+            }
+
+            match (tokens[0], &tokens[1..]) {
+                ("step", args) | ("s", args) => {
+                    let count = parse_count(args.first(), 1);
+                    self.execute(out, count)?;
+                }
+                ("back", args) | ("b", args) => {
+                    let count = parse_count(args.first(), 1);
+                    let mut undone = 0;
+                    while undone < count && self.history.step_back(&mut self.cpu) {
+                        undone += 1;
+                    }
+                    if undone < count {
+                        writeln!(out, "History exhausted after {} instructions.", undone)?;
+                    }
+                    self.print_state(out)?;
+                }
+                ("run", args) | ("r", args) => {
+                    let limit = parse_count(args.first(), u64::MAX);
+                    self.execute(out, limit)?;
+                }
+                ("last-write", [address]) | ("w", [address]) => {
+                    match parse_address(address) {
+                        Some(address) => self.print_last_write(out, address)?,
+                        None => writeln!(out, "Invalid address: {}", address)?,
+                    }
+                }
+                ("ram", args) | ("x", args) if !args.is_empty() => {
+                    match parse_address(args[0]) {
+                        Some(address) => {
+                            let count = parse_count(args.get(1), 1);
+                            for address in (address as u64..address as u64 + count).take_while(|&a| a < RAM_SIZE as u64) {
+                                let value = self.cpu.ram[address as usize];
+                                writeln!(out, "RAM[{}] = {}", address, value as i16)?;
+                            }
+                        }
+                        None => writeln!(out, "Invalid address: {}", args[0])?,
+                    }
+                }
+                ("watch", [range]) => self.add_watchpoint(out, range, Access::Write)?,
+                ("rwatch", [range]) => self.add_watchpoint(out, range, Access::Read)?,
+                ("awatch", [range]) => self.add_watchpoint(out, range, Access::ReadWrite)?,
+                ("unwatch", _) => self.breaks.watchpoints.clear(),
+                ("regs", _) => self.print_state(out)?,
+                ("save", [file_name]) => {
+                    let mut out_file = BufWriter::new(File::create(file_name)?);
+                    snapshot::save(&self.cpu, &mut out_file)?;
+                    out_file.flush()?;
+                    writeln!(out, "Saved {}.", file_name)?;
+                }
+                ("load", [file_name]) => {
+                    match File::open(file_name).and_then(|f| snapshot::load(&mut BufReader::new(f))) {
+                        Ok(cpu) => {
+                            self.cpu = cpu;
+                            self.history.clear();
+                            self.print_state(out)?;
+                        }
+                        Err(err) => writeln!(out, "Unable to load {}: {}", file_name, err)?,
+                    }
+                }
+                ("quit", _) | ("q", _) => return Ok(()),
+                _ => writeln!(out, "{}", HELP)?,
+            }
+
+            write!(out, "> ")?;
+            out.flush()?;
+        }
+
+        Ok(())
+    }
+
+    // Runs at most `count` instructions, stopping early on halt or a break
+    fn execute<W: Write>(&mut self, out: &mut W, count: u64) -> io::Result<()> {
+        let mut executed = 0;
+        while executed < count && !self.cpu.halted() {
+            let step = self.history.step(&mut self.cpu);
+            executed += 1;
+
+            if let Some(reason) = self.breaks.check(&step, &self.cpu) {
+                writeln!(out, "Break: {}", reason)?;
+                break;
+            }
+        }
+        self.print_state(out)
+    }
+
+    fn add_watchpoint<W: Write>(&mut self, out: &mut W, range: &str, access: Access) -> io::Result<()> {
+        let mut bounds = range.splitn(2, '-').map(parse_address);
+        let (start, end) = match (bounds.next(), bounds.next()) {
+            (Some(Some(start)), None) => (start, start + 1),
+            (Some(Some(start)), Some(Some(end))) if start <= end => (start, end + 1),
+            _ => return writeln!(out, "Invalid range: {}", range),
+        };
+
+        self.breaks.watchpoints.push(Watchpoint { start, end, access });
+        Ok(())
+    }
+
+    fn print_state<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        writeln!(out,
+                 "cycle {}  PC={}{}  A={} D={}  next: {}{}",
+                 self.cpu.cycles,
+                 self.cpu.pc,
+                 self.location(self.cpu.pc),
+                 self.cpu.a,
+                 self.cpu.d as i16,
+                 disassemble(self.cpu.fetch()),
+                 if self.cpu.halted() { "  (halted)" } else { "" })
+    }
+
+    fn print_last_write<W: Write>(&self, out: &mut W, address: u16) -> io::Result<()> {
+        match self.history.last_write(address) {
+            Some((age, pc, write)) => {
+                writeln!(out,
+                         "RAM[{}] set {} -> {} at cycle {} by PC={}{} ({} instructions ago)",
+                         address,
+                         write.previous as i16,
+                         write.value as i16,
+                         self.cpu.cycles + 1 - age as u64,
+                         pc,
+                         self.location(pc),
+                         age)
+            }
+            None => {
+                writeln!(out,
+                         "No write to RAM[{}] in the last {} instructions.",
+                         address,
+                         self.history.len())
+            }
+        }
+    }
+
+    // ` (label+offset)` for the closest label at or before `pc`
+    fn location(&self, pc: u16) -> String {
+        self.labels
+            .iter()
+            .filter(|&&(_, address)| address <= pc)
+            .max_by_key(|&&(_, address)| address)
+            .map_or(String::new(), |(label, address)| format!(" ({}+{})", label, pc - address))
+    }
+}
+
+fn parse_count(arg: Option<&&str>, default: u64) -> u64 {
+    arg.and_then(|count| count.parse().ok()).unwrap_or(default)
+}
+
+fn parse_address(arg: &str) -> Option<u16> {
+    let symbols = predefined_symbol_table();
+
+    match arg.parse::<u16>() {
+        Ok(address) if (address as usize) < RAM_SIZE => Some(address),
+        Ok(_) => None,
+        Err(_) if symbols.contains(arg) => Some(symbols.get_address(arg)),
+        Err(_) => None,
+    }
+}
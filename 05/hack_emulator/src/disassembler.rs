@@ -0,0 +1,63 @@
+pub fn disassemble(instruction: u16) -> String {
+    if instruction & 0x8000 == 0 {
+        return format!("@{}", instruction);
+    }
+
+    let comp = comp((instruction >> 6) & 0x7F);
+    let dest = match (instruction >> 3) & 0x7 {
+        0 => "",
+        1 => "M=",
+        2 => "D=",
+        3 => "MD=",
+        4 => "A=",
+        5 => "AM=",
+        6 => "AD=",
+        _ => "AMD=",
+    };
+    let jump = match instruction & 0x7 {
+        0 => "",
+        1 => ";JGT",
+        2 => ";JEQ",
+        3 => ";JGE",
+        4 => ";JLT",
+        5 => ";JNE",
+        6 => ";JLE",
+        _ => ";JMP",
+    };
+
+    format!("{}{}{}", dest, comp, jump)
+}
+
+fn comp(bits: u16) -> &'static str {
+    match bits {
+        0b0101010 => "0",
+        0b0111111 => "1",
+        0b0111010 => "-1",
+        0b0001100 => "D",
+        0b0110000 => "A",
+        0b0001101 => "!D",
+        0b0110001 => "!A",
+        0b0001111 => "-D",
+        0b0110011 => "-A",
+        0b0011111 => "D+1",
+        0b0110111 => "A+1",
+        0b0001110 => "D-1",
+        0b0110010 => "A-1",
+        0b0000010 => "D+A",
+        0b0010011 => "D-A",
+        0b0000111 => "A-D",
+        0b0000000 => "D&A",
+        0b0010101 => "D|A",
+        0b1110000 => "M",
+        0b1110001 => "!M",
+        0b1110011 => "-M",
+        0b1110111 => "M+1",
+        0b1110010 => "M-1",
+        0b1000010 => "D+M",
+        0b1010011 => "D-M",
+        0b1000111 => "M-D",
+        0b1000000 => "D&M",
+        0b1010101 => "D|M",
+        _ => "???",
+    }
+}
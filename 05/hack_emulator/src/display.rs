@@ -0,0 +1,77 @@
+use cpu::SCREEN;
+
+pub const WIDTH: usize = 512;
+pub const HEIGHT: usize = 256;
+
+pub enum Glyphs {
+    // 2x4 dots per character
+    Braille,
+    // 1x2 dots per character
+    HalfBlocks,
+}
+
+impl Glyphs {
+    pub fn dots(&self) -> (usize, usize) {
+        match *self {
+            Glyphs::Braille => (2, 4),
+            Glyphs::HalfBlocks => (1, 2),
+        }
+    }
+
+    /// Characters needed to show the screen with `scale`x`scale` pixels per dot.
+    pub fn size(&self, scale: usize) -> (usize, usize) {
+        let (dots_x, dots_y) = self.dots();
+        (WIDTH / (dots_x * scale), HEIGHT / (dots_y * scale))
+    }
+
+    fn glyph(&self, dots: u8) -> char {
+        match *self {
+            Glyphs::Braille => {
+                // Unicode numbers the dots down the left column first, with
+                // the bottom row tacked on last
+                const BITS: [u8; 8] = [0x01, 0x08, 0x02, 0x10, 0x04, 0x20, 0x40, 0x80];
+                let bits = (0..8)
+                    .filter(|&dot| dots & (1 << dot) != 0)
+                    .fold(0, |bits, dot| bits | BITS[dot]);
+                ::std::char::from_u32(0x2800 + bits as u32).unwrap()
+            }
+            Glyphs::HalfBlocks => [' ', '▀', '▄', '█'][dots as usize & 3],
+        }
+    }
+}
+
+/// Renders the memory mapped screen as text. Each dot covers a
+/// `scale`x`scale` block of pixels and is lit if any of them is black.
+pub fn render(ram: &[u16], glyphs: &Glyphs, scale: usize) -> Vec<String> {
+    let (dots_x, dots_y) = glyphs.dots();
+    let (columns, rows) = glyphs.size(scale);
+
+    (0..rows)
+        .map(|row| {
+            (0..columns)
+                .map(|column| {
+                    let mut dots = 0;
+                    for dot_y in 0..dots_y {
+                        for dot_x in 0..dots_x {
+                            let x = (column * dots_x + dot_x) * scale;
+                            let y = (row * dots_y + dot_y) * scale;
+                            if block_lit(ram, x, y, scale) {
+                                dots |= 1 << (dot_y * dots_x + dot_x);
+                            }
+                        }
+                    }
+                    glyphs.glyph(dots)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn block_lit(ram: &[u16], x: usize, y: usize, scale: usize) -> bool {
+    (y..y + scale).any(|y| (x..x + scale).any(|x| pixel(ram, x, y)))
+}
+
+fn pixel(ram: &[u16], x: usize, y: usize) -> bool {
+    let word = ram[SCREEN as usize + y * WIDTH / 16 + x / 16];
+    word & (1 << (x % 16)) != 0
+}
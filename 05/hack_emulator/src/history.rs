@@ -0,0 +1,82 @@
+use std::collections::VecDeque;
+
+use cpu::{Cpu, MemoryWrite, Step};
+
+// Everything needed to undo one instruction
+struct Undo {
+    pc: u16,
+    a: u16,
+    d: u16,
+    write: Option<MemoryWrite>,
+}
+
+/// Bounded record of recently executed instructions, oldest dropped first.
+pub struct History {
+    undo: VecDeque<Undo>,
+    capacity: usize,
+}
+
+impl History {
+    pub fn new(capacity: usize) -> Self {
+        History {
+            undo: VecDeque::with_capacity(capacity.min(1 << 20)),
+            capacity,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.undo.len()
+    }
+
+    pub fn clear(&mut self) {
+        self.undo.clear();
+    }
+
+    /// Executes one instruction on the CPU, remembering how to undo it.
+    pub fn step(&mut self, cpu: &mut Cpu) -> Step {
+        let (pc, a, d) = (cpu.pc, cpu.a, cpu.d);
+        let step = cpu.step();
+
+        if self.capacity > 0 {
+            if self.undo.len() == self.capacity {
+                self.undo.pop_front();
+            }
+            self.undo.push_back(Undo {
+                pc,
+                a,
+                d,
+                write: step.write,
+            });
+        }
+
+        step
+    }
+
+    /// Undoes the most recent instruction, false once history runs out.
+    pub fn step_back(&mut self, cpu: &mut Cpu) -> bool {
+        match self.undo.pop_back() {
+            Some(undo) => {
+                if let Some(write) = undo.write {
+                    cpu.ram[write.address as usize] = write.previous;
+                }
+                cpu.pc = undo.pc;
+                cpu.a = undo.a;
+                cpu.d = undo.d;
+                cpu.cycles -= 1;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Finds the most recent recorded write to `address`. Returns how many
+    /// instructions ago it happened together with the writing PC and value.
+    pub fn last_write(&self, address: u16) -> Option<(usize, u16, &MemoryWrite)> {
+        self.undo
+            .iter()
+            .rev()
+            .enumerate()
+            .filter_map(|(age, undo)| undo.write.as_ref().map(|write| (age + 1, undo.pc, write)))
+            .find(|&(_, _, write)| write.address == address)
+    }
+}
@@ -0,0 +1,5 @@
+extern crate assembler;
+
+pub mod cpu;
+
+pub mod program;
@@ -0,0 +1,278 @@
+use std::env;
+use std::fs::File;
+use std::io;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use std::process;
+use std::time::Duration;
+
+extern crate getopts;
+use getopts::Options;
+use getopts::ParsingStyle;
+
+extern crate assembler;
+extern crate termion;
+
+extern crate hack_emulator;
+use hack_emulator::{cpu, program};
+use cpu::Cpu;
+use program::Program;
+
+mod disassembler;
+
+mod tracer;
+use tracer::Tracer;
+
+mod profiler;
+use profiler::Profiler;
+
+mod history;
+use history::History;
+
+mod snapshot;
+
+mod watch;
+use watch::{Access, Breaks, VmChecker, Watchpoint};
+
+mod debugger;
+use debugger::Debugger;
+
+mod display;
+use display::Glyphs;
+
+mod tui;
+use tui::TuiOptions;
+
+mod source_map;
+
+mod coverage;
+use coverage::Coverage;
+
+mod test_script;
+use test_script::TestScript;
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let program = args[0].clone();
+
+    let mut opts = Options::new();
+    opts.optopt("c", "cycles", "stop after this many cycles", "CYCLES");
+    opts.optopt("", "trace", "write an instruction trace to this file", "TRACEFILE");
+    opts.optmulti("", "trace-range", "only trace ROM addresses in LO-HI", "LO-HI");
+    opts.optmulti("", "trace-label", "only trace the region starting at LABEL", "LABEL");
+    opts.optflag("p", "profile", "print the cycles spent in each label region");
+    opts.optmulti("", "profile-merge", "fold labels containing PATTERN into the enclosing region", "PATTERN");
+    opts.optopt("", "top", "number of regions to print when profiling", "N");
+    opts.optopt("", "save-snapshot", "save the machine state here when stopping", "SNAPFILE");
+    opts.optmulti("", "watch", "stop on writes to RAM addresses in LO-HI", "LO-HI");
+    opts.optmulti("", "rwatch", "stop on reads of RAM addresses in LO-HI", "LO-HI");
+    opts.optmulti("", "awatch", "stop on any access to RAM addresses in LO-HI", "LO-HI");
+    opts.optflag("", "check-vm", "stop when the VM stack, heap or screen conventions are broken");
+    opts.optmulti("", "screen-owner", "class allowed to write the screen (default Screen)", "CLASS");
+    opts.optflag("d", "debug", "step through the program interactively");
+    opts.optopt("", "history", "instructions the debugger can step back (default 1000000)", "N");
+    opts.optflag("t", "tui", "run full screen in the terminal with a live screen and keyboard");
+    opts.optflag("", "half-blocks", "draw the screen with half blocks instead of braille");
+    opts.optopt("", "scale", "pixels per screen dot in the TUI (1, 2, 4 or 8)", "N");
+    opts.optopt("", "key-hold", "milliseconds a key stays pressed in the TUI (default 150)", "MS");
+    opts.optopt("", "speed", "instructions per second in the TUI (default unlimited)", "HZ");
+    opts.optopt("", "ram-view", "first RAM address shown in the TUI (default follows SP)", "ADDR");
+    opts.optopt("", "coverage", "write an lcov coverage report to this file", "LCOVFILE");
+    opts.parsing_style(ParsingStyle::FloatingFrees);
+
+    let matches = opts.parse(&args[1..]).expect("Unable to parse arguments.");
+
+    let in_path = if matches.free.len() == 1 {
+        Path::new(&matches.free[0])
+    } else {
+        let brief = format!("Usage: {} FILE [options]", program);
+        println!("{}", opts.usage(&brief));
+        return;
+    };
+
+    if in_path.extension().is_some_and(|ext| ext == "tst") {
+        run_test_script(in_path, matches.opt_str("coverage"));
+        return;
+    }
+
+    // Snapshots carry the ROM but no labels
+    let (program, mut cpu) = if in_path.extension().is_some_and(|ext| ext == "snap") {
+        let in_file = File::open(in_path).expect("Unable to find file.");
+        let cpu = snapshot::load(&mut BufReader::new(in_file)).expect("Unable to read snapshot.");
+        let program = Program {
+            rom: cpu.rom.clone(),
+            labels: Vec::new(),
+        };
+        (program, cpu)
+    } else {
+        let program = Program::load(in_path);
+        let cpu = Cpu::new(program.rom.clone());
+        (program, cpu)
+    };
+    let needs_labels = matches.opt_present("trace-label") || matches.opt_present("profile");
+    if needs_labels && program.labels.is_empty() {
+        panic!("Labels are only available when running an .asm file.");
+    }
+
+    let mut breaks = Breaks::default();
+    for &(option, access) in &[("watch", Access::Write),
+                               ("rwatch", Access::Read),
+                               ("awatch", Access::ReadWrite)] {
+        for range in matches.opt_strs(option) {
+            let (start, end) = parse_range(&range);
+            breaks.watchpoints.push(Watchpoint { start, end, access });
+        }
+    }
+    if matches.opt_present("check-vm") {
+        if program.labels.is_empty() {
+            panic!("VM checks are only available when running an .asm file.");
+        }
+        let mut screen_owners = matches.opt_strs("screen-owner");
+        if screen_owners.is_empty() {
+            screen_owners.push("Screen".to_string());
+        }
+        breaks.checker = Some(VmChecker::new(&program, screen_owners));
+    }
+
+    if matches.opt_present("debug") {
+        let capacity = matches.opt_str("history")
+            .map_or(1_000_000, |history| history.parse().expect("Invalid history size."));
+        let mut debugger = Debugger::new(cpu, History::new(capacity), breaks, &program.labels);
+        let stdin = io::stdin();
+        debugger.run(stdin.lock(), &mut io::stdout()).expect("Unable to run debugger.");
+        return;
+    }
+
+    if matches.opt_present("tui") {
+        let options = TuiOptions {
+            glyphs: if matches.opt_present("half-blocks") {
+                Glyphs::HalfBlocks
+            } else {
+                Glyphs::Braille
+            },
+            scale: matches.opt_str("scale").map(|scale| scale.parse().expect("Invalid scale.")),
+            key_hold: Duration::from_millis(matches.opt_str("key-hold")
+                .map_or(150, |hold| hold.parse().expect("Invalid key hold."))),
+            ram_view: matches.opt_str("ram-view")
+                .map(|address| address.parse().expect("Invalid address.")),
+            speed: matches.opt_str("speed").map(|speed| speed.parse().expect("Invalid speed.")),
+        };
+        tui::run(cpu, &breaks, &program.labels, &options).expect("Unable to run TUI.");
+        return;
+    }
+
+    let max_cycles = matches.opt_str("cycles")
+        .map_or(u64::MAX, |cycles| cycles.parse().expect("Invalid cycle count."));
+
+    let mut tracer = matches.opt_str("trace").map(|trace_file| {
+        let mut ranges: Vec<(u16, u16)> = matches.opt_strs("trace-range")
+            .iter()
+            .map(|range| parse_range(range))
+            .collect();
+        for label in matches.opt_strs("trace-label") {
+            ranges.push(program.label_region(&label)
+                .unwrap_or_else(|| panic!("Unknown label: {}", label)));
+        }
+
+        let out_file = File::create(trace_file).expect("Unable to create file.");
+        Tracer::new(BufWriter::new(out_file), ranges)
+    });
+
+    let mut profiler = if matches.opt_present("profile") {
+        Some(Profiler::new(&program, &matches.opt_strs("profile-merge")))
+    } else {
+        None
+    };
+
+    let coverage_file = matches.opt_str("coverage");
+    let mut coverage = coverage_file.as_ref().map(|_| Coverage::new(&program.rom));
+
+    while cpu.cycles < max_cycles && !cpu.halted() {
+        let step = cpu.step();
+
+        if let Some(ref mut tracer) = tracer {
+            tracer.record(&step, &cpu).expect("Unable to write to file.");
+        }
+        if let Some(ref mut profiler) = profiler {
+            profiler.record(step.pc);
+        }
+        if let Some(ref mut coverage) = coverage {
+            coverage.record(&step, &cpu);
+        }
+        if let Some(reason) = breaks.check(&step, &cpu) {
+            println!("Break: {}", reason);
+            break;
+        }
+    }
+
+    if let Some(ref mut tracer) = tracer {
+        tracer.flush().expect("Unable to write to file.");
+    }
+
+    if let Some(snapshot_file) = matches.opt_str("save-snapshot") {
+        let mut out_file = BufWriter::new(File::create(snapshot_file).expect("Unable to create file."));
+        snapshot::save(&cpu, &mut out_file)
+            .and_then(|_| out_file.flush())
+            .expect("Unable to write to file.");
+    }
+
+    if cpu.halted() {
+        println!("Halted after {} cycles.", cpu.cycles);
+    } else {
+        println!("Stopped after {} cycles.", cpu.cycles);
+    }
+
+    if let Some(profiler) = profiler {
+        let top = matches.opt_str("top").map_or(20, |top| top.parse().expect("Invalid count."));
+        profiler.report(&mut io::stdout(), top).expect("Unable to write profile.");
+    }
+
+    if let (Some(coverage), Some(coverage_file)) = (coverage, coverage_file) {
+        write_coverage(&coverage, in_path, &coverage_file);
+    }
+}
+
+fn run_test_script(script_path: &Path, coverage_file: Option<String>) {
+    let mut source = String::new();
+    File::open(script_path)
+        .and_then(|mut in_file| in_file.read_to_string(&mut source))
+        .expect("Unable to read file.");
+
+    let directory = script_path.parent().unwrap_or_else(|| Path::new("."));
+    let mut script = TestScript::new(directory, coverage_file.is_some());
+    let result = script.run(&source);
+
+    if let (Some((coverage, program_path)), Some(coverage_file)) = (script.coverage(), coverage_file) {
+        write_coverage(coverage, program_path, &coverage_file);
+    }
+
+    match result {
+        Ok(()) if script.is_comparing() => println!("End of script - Comparison ended successfully"),
+        Ok(()) => println!("End of script"),
+        Err(message) => {
+            println!("{}", message);
+            process::exit(1);
+        }
+    }
+}
+
+fn write_coverage(coverage: &Coverage, program_path: &Path, coverage_file: &str) {
+    let mut out_file = BufWriter::new(File::create(coverage_file).expect("Unable to create file."));
+    coverage.write_lcov(&mut out_file, program_path)
+        .and_then(|_| out_file.flush())
+        .expect("Unable to write to file.");
+    println!("{}", coverage.summary());
+}
+
+// Parses an inclusive `LO-HI` range into a half-open one
+fn parse_range(range: &str) -> (u16, u16) {
+    // RAM and ROM both hold 32K words
+    let mut bounds = range.splitn(2, '-')
+        .map(|bound| bound.trim().parse::<u16>().ok().filter(|&address| (address as usize) < cpu::RAM_SIZE));
+
+    match (bounds.next(), bounds.next()) {
+        (Some(Some(lo)), Some(Some(hi))) if lo <= hi => (lo, hi + 1),
+        (Some(Some(lo)), None) => (lo, lo + 1),
+        _ => panic!("Invalid range: {}", range),
+    }
+}
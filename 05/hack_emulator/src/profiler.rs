@@ -0,0 +1,105 @@
+use std::io;
+use std::io::Write;
+
+use program::Program;
+
+pub struct Profiler {
+    names: Vec<String>,
+    starts: Vec<u16>,
+    // Region index for every ROM address
+    region_of: Vec<usize>,
+    cycles: Vec<u64>,
+    entries: Vec<u64>,
+    previous_pc: Option<u16>,
+}
+
+impl Profiler {
+    /// Splits the ROM into regions starting at each label. Labels containing
+    /// any of the `merge` patterns do not start a region of their own, which
+    /// folds e.g. `$`-scoped VM labels into their function.
+    pub fn new(program: &Program, merge: &[String]) -> Self {
+        let mut labels: Vec<&(String, u16)> = program.labels
+            .iter()
+            .filter(|(label, _)| !merge.iter().any(|pattern| label.contains(pattern.as_str())))
+            .collect();
+        // Stable, so the first label declared at an address names the region
+        labels.sort_by_key(|&&(_, address)| address);
+        labels.dedup_by_key(|&mut &(_, address)| address);
+
+        let mut names = Vec::new();
+        let mut starts = Vec::new();
+        if labels.first().is_none_or(|&&(_, address)| address > 0) {
+            names.push("<start>".to_string());
+            starts.push(0);
+        }
+        for &&(ref label, address) in &labels {
+            names.push(label.clone());
+            starts.push(address);
+        }
+
+        let mut region_of = Vec::with_capacity(program.rom.len());
+        let mut region = 0;
+        for address in 0..program.rom.len() {
+            while region + 1 < starts.len() && starts[region + 1] as usize <= address {
+                region += 1;
+            }
+            region_of.push(region);
+        }
+
+        Profiler {
+            cycles: vec![0; names.len()],
+            entries: vec![0; names.len()],
+            names,
+            starts,
+            region_of,
+            previous_pc: None,
+        }
+    }
+
+    pub fn record(&mut self, pc: u16) {
+        let region = match self.region_of.get(pc as usize) {
+            Some(&region) => region,
+            None => return,
+        };
+
+        // Count every arrival at the region start other than falling
+        // through from the instruction right before it inside the region
+        self.cycles[region] += 1;
+        if self.starts[region] == pc &&
+           self.previous_pc.is_none_or(|previous| previous.wrapping_add(1) != pc ||
+                                                  self.region_of[previous as usize] != region) {
+            self.entries[region] += 1;
+        }
+        self.previous_pc = Some(pc);
+    }
+
+    pub fn report<W: Write>(&self, out: &mut W, top: usize) -> io::Result<()> {
+        let total: u64 = self.cycles.iter().sum();
+        let mut regions: Vec<usize> = (0..self.names.len())
+            .filter(|&region| self.cycles[region] > 0)
+            .collect();
+        regions.sort_by(|&a, &b| self.cycles[b].cmp(&self.cycles[a]));
+
+        writeln!(out,
+                 "{:>12} {:>7} {:>10} {:>12}  region",
+                 "cycles",
+                 "%",
+                 "entries",
+                 "cycles/entry")?;
+        for &region in regions.iter().take(top) {
+            let per_entry = if self.entries[region] > 0 {
+                format!("{:.1}", self.cycles[region] as f64 / self.entries[region] as f64)
+            } else {
+                "-".to_string()
+            };
+            writeln!(out,
+                     "{:>12} {:>6.2}% {:>10} {:>12}  {}",
+                     self.cycles[region],
+                     100.0 * self.cycles[region] as f64 / total.max(1) as f64,
+                     self.entries[region],
+                     per_entry,
+                     self.names[region])?;
+        }
+        writeln!(out, "{:>12} total cycles", total)
+    }
+}
@@ -0,0 +1,82 @@
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use assembler::{assemble, parse_labels, rom_labels};
+
+pub struct Program {
+    pub rom: Vec<u16>,
+    // Labels in source order, empty when loaded from a .hack file
+    pub labels: Vec<(String, u16)>,
+}
+
+impl Program {
+    pub fn load(path: &Path) -> Program {
+        let mut in_file = File::open(path).expect("Unable to find file.");
+        let mut buffer = String::new();
+        in_file.read_to_string(&mut buffer).expect("Unable to read file.");
+
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("asm") => Program::from_asm(&buffer),
+            _ => Program::from_hack(&buffer),
+        }
+    }
+
+    pub fn from_asm(buffer: &str) -> Program {
+        let labels = rom_labels(buffer)
+            .into_iter()
+            .map(|(label, address)| (label.to_string(), address))
+            .collect();
+        let hack = assemble(buffer, parse_labels(buffer));
+
+        Program {
+            rom: Program::from_hack(&hack).rom,
+            labels,
+        }
+    }
+
+    pub fn from_hack(buffer: &str) -> Program {
+        let rom = buffer.lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                u16::from_str_radix(line, 2)
+                    .unwrap_or_else(|_| panic!("Invalid instruction: {}", line))
+            })
+            .collect();
+
+        Program {
+            rom,
+            labels: Vec::new(),
+        }
+    }
+
+    pub fn label_address(&self, name: &str) -> Option<u16> {
+        self.labels.iter().find(|(label, _)| label == name).map(|&(_, address)| address)
+    }
+
+    /// ROM addresses from the label up to the next label further on.
+    pub fn label_region(&self, name: &str) -> Option<(u16, u16)> {
+        self.label_address(name).map(|start| {
+            let end = self.labels
+                .iter()
+                .map(|&(_, address)| address)
+                .filter(|&address| address > start)
+                .min()
+                .unwrap_or(self.rom.len() as u16);
+            (start, end)
+        })
+    }
+
+    /// VM function entry points, i.e. `Class.name` labels without a `$`,
+    /// sorted by address.
+    pub fn functions(&self) -> Vec<(u16, String)> {
+        let mut functions: Vec<(u16, String)> = self.labels
+            .iter()
+            .filter(|(label, _)| label.contains('.') && !label.contains('$'))
+            .map(|(label, address)| (*address, label.clone()))
+            .collect();
+        functions.sort();
+        functions
+    }
+}
@@ -0,0 +1,113 @@
+//! Machine state snapshots.
+//!
+//! All words are little endian:
+//!
+//! ```text
+//! "HACKSNAP" version:u8
+//! a:u16 d:u16 pc:u16 cycles:u64
+//! rom_len:u16 rom:[u16; rom_len]
+//! { start:u16 len:u16 words:[u16; len] }*  0:u16 0:u16
+//! ```
+//!
+//! RAM is stored as runs of non-zero words, so a freshly booted program
+//! costs a few hundred bytes rather than 64K.
+
+use std::io;
+use std::io::{Read, Write};
+
+use cpu::{Cpu, RAM_SIZE};
+
+const MAGIC: &[u8; 8] = b"HACKSNAP";
+const VERSION: u8 = 1;
+
+pub fn save<W: Write>(cpu: &Cpu, out: &mut W) -> io::Result<()> {
+    out.write_all(MAGIC)?;
+    out.write_all(&[VERSION])?;
+    write_u16(out, cpu.a)?;
+    write_u16(out, cpu.d)?;
+    write_u16(out, cpu.pc)?;
+    out.write_all(&cpu.cycles.to_le_bytes())?;
+
+    write_u16(out, cpu.rom.len() as u16)?;
+    for &word in &cpu.rom {
+        write_u16(out, word)?;
+    }
+
+    let mut address = 0;
+    while address < RAM_SIZE {
+        if cpu.ram[address] == 0 {
+            address += 1;
+            continue;
+        }
+
+        let start = address;
+        while address < RAM_SIZE && cpu.ram[address] != 0 {
+            address += 1;
+        }
+        write_u16(out, start as u16)?;
+        write_u16(out, (address - start) as u16)?;
+        for &word in &cpu.ram[start..address] {
+            write_u16(out, word)?;
+        }
+    }
+    write_u16(out, 0)?;
+    write_u16(out, 0)
+}
+
+pub fn load<R: Read>(input: &mut R) -> io::Result<Cpu> {
+    let mut magic = [0; 8];
+    input.read_exact(&mut magic)?;
+    let mut version = [0; 1];
+    input.read_exact(&mut version)?;
+    if &magic != MAGIC || version[0] != VERSION {
+        return Err(invalid("not a Hack snapshot"));
+    }
+
+    let a = read_u16(input)?;
+    let d = read_u16(input)?;
+    let pc = read_u16(input)?;
+    let mut cycles = [0; 8];
+    input.read_exact(&mut cycles)?;
+
+    let rom_len = read_u16(input)?;
+    let mut rom = Vec::with_capacity(rom_len as usize);
+    for _ in 0..rom_len {
+        rom.push(read_u16(input)?);
+    }
+
+    let mut cpu = Cpu::new(rom);
+    cpu.a = a;
+    cpu.d = d;
+    cpu.pc = pc;
+    cpu.cycles = u64::from_le_bytes(cycles);
+
+    loop {
+        let start = read_u16(input)? as usize;
+        let len = read_u16(input)? as usize;
+        if len == 0 {
+            break;
+        }
+        if start + len > RAM_SIZE {
+            return Err(invalid("RAM run out of range"));
+        }
+        for word in &mut cpu.ram[start..start + len] {
+            *word = read_u16(input)?;
+        }
+    }
+
+    Ok(cpu)
+}
+
+fn write_u16<W: Write>(out: &mut W, word: u16) -> io::Result<()> {
+    out.write_all(&word.to_le_bytes())
+}
+
+fn read_u16<R: Read>(input: &mut R) -> io::Result<u16> {
+    let mut bytes = [0; 2];
+    input.read_exact(&mut bytes)?;
+    Ok(u16::from_le_bytes(bytes))
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
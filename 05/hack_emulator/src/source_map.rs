@@ -0,0 +1,46 @@
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+/// Relates lines of a generated file to the source lines they came from.
+/// `vm_translator -m` writes one for .asm files and `jack_compiler -m`
+/// for .vm files. Each line of the `.map` file reads
+/// `GENERATED_LINE SOURCE_FILE SOURCE_LINE` and covers generated lines up
+/// to the next entry.
+pub struct SourceMap {
+    entries: Vec<(usize, String, usize)>,
+}
+
+impl SourceMap {
+    /// Loads `<generated>.map`, if there is one.
+    pub fn load(generated: &Path) -> Option<SourceMap> {
+        let mut map_path = generated.as_os_str().to_owned();
+        map_path.push(".map");
+
+        let mut buffer = String::new();
+        File::open(map_path).ok()?.read_to_string(&mut buffer).ok()?;
+
+        let mut entries: Vec<(usize, String, usize)> = buffer.lines()
+            .filter_map(|line| {
+                let mut fields = line.split_whitespace();
+                let generated_line = fields.next()?.parse().ok()?;
+                let source_file = fields.next()?.to_string();
+                let source_line = fields.next()?.parse().ok()?;
+                Some((generated_line, source_file, source_line))
+            })
+            .collect();
+        entries.sort_by_key(|&(generated_line, _, _)| generated_line);
+
+        Some(SourceMap { entries })
+    }
+
+    pub fn lookup(&self, line: usize) -> Option<(&str, usize)> {
+        let index = match self.entries.binary_search_by_key(&line, |&(generated_line, _, _)| generated_line) {
+            Ok(index) => index,
+            Err(0) => return None,
+            Err(index) => index - 1,
+        };
+        let (_, ref source_file, source_line) = self.entries[index];
+        Some((source_file, source_line))
+    }
+}
@@ -0,0 +1,408 @@
+//! Runs the subset of the nand2tetris CPU emulator test script language
+//! used by the course's .tst files: `load`, `output-file`, `compare-to`,
+//! `output-list`, `set`, `repeat`, `while`, `ticktock`, `output` and `echo`.
+
+use std::fs::File;
+use std::io::{BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+
+use coverage::Coverage;
+use cpu::{Cpu, RAM_SIZE};
+use program::Program;
+
+enum Command {
+    Load(String),
+    OutputFile(String),
+    CompareTo(String),
+    OutputList(Vec<String>),
+    Set(String, String),
+    Repeat(u64, Vec<Command>),
+    While(Condition, Vec<Command>),
+    TickTock,
+    Output,
+    Echo(String),
+    Nothing,
+}
+
+struct Condition {
+    left: String,
+    operator: String,
+    right: String,
+}
+
+struct Column {
+    name: String,
+    format: char,
+    left: usize,
+    width: usize,
+    right: usize,
+}
+
+pub struct TestScript {
+    directory: PathBuf,
+    cpu: Option<Cpu>,
+    program_path: Option<PathBuf>,
+    output: Option<BufWriter<File>>,
+    compare: Option<Vec<String>>,
+    columns: Vec<Column>,
+    output_lines: usize,
+    collect_coverage: bool,
+    coverage: Option<Coverage>,
+}
+
+impl TestScript {
+    /// Files named by the script are looked up relative to `directory`.
+    pub fn new(directory: &Path, collect_coverage: bool) -> Self {
+        TestScript {
+            directory: directory.to_path_buf(),
+            cpu: None,
+            program_path: None,
+            output: None,
+            compare: None,
+            columns: Vec::new(),
+            output_lines: 0,
+            collect_coverage,
+            coverage: None,
+        }
+    }
+
+    /// Runs the script, failing on errors and on the first output line
+    /// that differs from the compare file.
+    pub fn run(&mut self, source: &str) -> Result<(), String> {
+        let tokens = tokenize(source);
+        let mut position = 0;
+        let commands = parse_block(&tokens, &mut position, false)?;
+
+        let result = self.execute(&commands);
+        if let Some(ref mut output) = self.output {
+            output.flush().map_err(|err| err.to_string())?;
+        }
+        result
+    }
+
+    pub fn is_comparing(&self) -> bool {
+        self.compare.is_some()
+    }
+
+    /// Coverage of the program loaded last, when collecting it.
+    pub fn coverage(&self) -> Option<(&Coverage, &Path)> {
+        match (self.coverage.as_ref(), self.program_path.as_ref()) {
+            (Some(coverage), Some(path)) => Some((coverage, path)),
+            _ => None,
+        }
+    }
+
+    fn execute(&mut self, commands: &[Command]) -> Result<(), String> {
+        for command in commands {
+            match *command {
+                Command::Load(ref file_name) => {
+                    let path = self.directory.join(file_name);
+                    if !path.is_file() {
+                        return Err(format!("Unable to find {}", path.display()));
+                    }
+                    let program = Program::load(&path);
+                    if self.collect_coverage {
+                        self.coverage = Some(Coverage::new(&program.rom));
+                    }
+                    self.cpu = Some(Cpu::new(program.rom));
+                    self.program_path = Some(path);
+                }
+                Command::OutputFile(ref file_name) => {
+                    let out_file = File::create(self.directory.join(file_name))
+                        .map_err(|err| format!("Unable to create {}: {}", file_name, err))?;
+                    self.output = Some(BufWriter::new(out_file));
+                }
+                Command::CompareTo(ref file_name) => {
+                    let mut buffer = String::new();
+                    File::open(self.directory.join(file_name))
+                        .and_then(|mut in_file| in_file.read_to_string(&mut buffer))
+                        .map_err(|err| format!("Unable to read {}: {}", file_name, err))?;
+                    self.compare = Some(buffer.lines().map(|line| line.trim_end().to_string()).collect());
+                }
+                Command::OutputList(ref specs) => {
+                    self.columns = specs.iter().map(|spec| parse_column(spec)).collect::<Result<_, _>>()?;
+                    let header = self.columns
+                        .iter()
+                        .map(|column| {
+                            let total = column.left + column.width + column.right;
+                            let name: String = column.name.chars().take(total).collect();
+                            let left = (total - name.len()) / 2;
+                            format!("{}{}{}", " ".repeat(left), name, " ".repeat(total - name.len() - left))
+                        })
+                        .collect::<Vec<_>>();
+                    self.write_line(&header)?;
+                }
+                Command::Set(ref target, ref value) => {
+                    let value = parse_value(value)?;
+                    self.write(target, value)?;
+                }
+                Command::Repeat(count, ref body) => {
+                    for _ in 0..count {
+                        self.execute(body)?;
+                    }
+                }
+                Command::While(ref condition, ref body) => {
+                    while self.holds(condition)? {
+                        self.execute(body)?;
+                    }
+                }
+                Command::TickTock => {
+                    let cpu = self.cpu.as_mut().ok_or("No program loaded")?;
+                    let step = cpu.step();
+                    if let Some(ref mut coverage) = self.coverage {
+                        coverage.record(&step, cpu);
+                    }
+                }
+                Command::Output => {
+                    let mut fields = Vec::new();
+                    for column in &self.columns {
+                        let value = self.read(&column.name)?;
+                        let text = match column.format {
+                            'X' => format!("{:0width$X}", value, width = column.width),
+                            'B' => {
+                                let bits = format!("{:016b}", value);
+                                bits[16 - column.width.min(16)..].to_string()
+                            }
+                            _ => format!("{:>width$}", value as i16, width = column.width),
+                        };
+                        fields.push(format!("{}{}{}", " ".repeat(column.left), text, " ".repeat(column.right)));
+                    }
+                    self.write_line(&fields)?;
+                }
+                Command::Echo(ref text) => println!("{}", text),
+                Command::Nothing => (),
+            }
+        }
+        Ok(())
+    }
+
+    fn write_line(&mut self, fields: &[String]) -> Result<(), String> {
+        let line = format!("|{}|", fields.join("|"));
+        self.output_lines += 1;
+
+        if let Some(ref mut output) = self.output {
+            writeln!(output, "{}", line).map_err(|err| err.to_string())?;
+        }
+        if let Some(ref compare) = self.compare {
+            if compare.get(self.output_lines - 1).map(|expected| expected.as_str()) != Some(line.trim_end()) {
+                return Err(format!("Comparison failure at line {}", self.output_lines));
+            }
+        }
+        Ok(())
+    }
+
+    fn holds(&self, condition: &Condition) -> Result<bool, String> {
+        let left = self.read(&condition.left)? as i16;
+        let right = match parse_value(&condition.right) {
+            Ok(value) => value,
+            Err(_) => self.read(&condition.right)?,
+        } as i16;
+
+        Ok(match condition.operator.as_str() {
+            "=" => left == right,
+            "<>" => left != right,
+            "<" => left < right,
+            ">" => left > right,
+            "<=" => left <= right,
+            _ => left >= right,
+        })
+    }
+
+    fn read(&self, name: &str) -> Result<u16, String> {
+        let cpu = self.cpu.as_ref().ok_or("No program loaded")?;
+
+        match name {
+            "A" => Ok(cpu.a),
+            "D" => Ok(cpu.d),
+            "PC" => Ok(cpu.pc),
+            "time" => Ok(cpu.cycles as u16),
+            _ => ram_address(name).map(|address| cpu.ram[address]),
+        }
+    }
+
+    fn write(&mut self, name: &str, value: u16) -> Result<(), String> {
+        let cpu = self.cpu.as_mut().ok_or("No program loaded")?;
+
+        match name {
+            "A" => cpu.a = value,
+            "D" => cpu.d = value,
+            "PC" => cpu.pc = value,
+            _ => cpu.ram[ram_address(name)?] = value,
+        }
+        Ok(())
+    }
+}
+
+fn ram_address(name: &str) -> Result<usize, String> {
+    name.strip_prefix("RAM[")
+        .and_then(|rest| rest.strip_suffix(']'))
+        .and_then(|index| index.parse::<usize>().ok())
+        .filter(|&address| address < RAM_SIZE)
+        .ok_or_else(|| format!("Unknown variable: {}", name))
+}
+
+// `%X`, `%B` and `%D` prefixed or plain decimal, negatives wrap around
+fn parse_value(value: &str) -> Result<u16, String> {
+    let parsed = if let Some(hex) = value.strip_prefix("%X") {
+        i32::from_str_radix(hex, 16)
+    } else if let Some(binary) = value.strip_prefix("%B") {
+        i32::from_str_radix(binary, 2)
+    } else {
+        value.trim_start_matches("%D").parse::<i32>()
+    };
+
+    parsed.map(|value| value as u16).map_err(|_| format!("Invalid value: {}", value))
+}
+
+// `name%D1.6.1` is the variable, format and left pad, width, right pad
+fn parse_column(spec: &str) -> Result<Column, String> {
+    let mut parts = spec.splitn(2, '%');
+    let name = parts.next().unwrap().to_string();
+    let format = parts.next().unwrap_or("D1.6.1");
+
+    let mut chars = format.chars();
+    let kind = chars.next().ok_or_else(|| format!("Invalid output format: {}", spec))?;
+    let sizes: Vec<usize> = chars.as_str()
+        .split('.')
+        .map(|size| size.parse())
+        .collect::<Result<_, _>>()
+        .map_err(|_| format!("Invalid output format: {}", spec))?;
+    if sizes.len() != 3 {
+        return Err(format!("Invalid output format: {}", spec));
+    }
+
+    Ok(Column {
+        name,
+        format: kind,
+        left: sizes[0],
+        width: sizes[1],
+        right: sizes[2],
+    })
+}
+
+fn tokenize(source: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut chars = source.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '/' if chars.peek() == Some(&'/') => {
+                while chars.peek().is_some_and(|&c| c != '\n') {
+                    chars.next();
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut previous = ' ';
+                for c in chars.by_ref() {
+                    if previous == '*' && c == '/' {
+                        break;
+                    }
+                    previous = c;
+                }
+            }
+            '"' => {
+                let text: String = chars.by_ref().take_while(|&c| c != '"').collect();
+                tokens.push(format!("\"{}", text));
+            }
+            '{' | '}' | ',' | ';' => tokens.push(c.to_string()),
+            c if c.is_whitespace() => (),
+            c => {
+                let mut word = c.to_string();
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() || "{},;\"".contains(c) {
+                        break;
+                    }
+                    word.push(c);
+                    chars.next();
+                }
+                tokens.push(word);
+            }
+        }
+    }
+
+    tokens
+}
+
+fn parse_block(tokens: &[String], position: &mut usize, nested: bool) -> Result<Vec<Command>, String> {
+    let mut commands = Vec::new();
+
+    loop {
+        let token = match tokens.get(*position) {
+            Some(token) => token.as_str(),
+            None if nested => return Err("Missing }".to_string()),
+            None => return Ok(commands),
+        };
+
+        match token {
+            "}" if nested => {
+                *position += 1;
+                return Ok(commands);
+            }
+            "}" => return Err("Unexpected }".to_string()),
+            "," | ";" => *position += 1,
+            _ => {
+                let start = *position;
+                while tokens.get(*position).is_some_and(|token| !["{", "}", ",", ";"].contains(&token.as_str())) {
+                    *position += 1;
+                }
+                let words: Vec<&str> = tokens[start..*position].iter().map(|word| word.as_str()).collect();
+                commands.push(parse_command(&words, tokens, position)?);
+            }
+        }
+    }
+}
+
+fn parse_command(words: &[&str], tokens: &[String], position: &mut usize) -> Result<Command, String> {
+    let argument = |index: usize| {
+        words.get(index)
+            .map(|word| word.to_string())
+            .ok_or_else(|| format!("Missing argument to {}", words[0]))
+    };
+
+    let command = match words[0] {
+        "load" => Command::Load(argument(1)?),
+        "output-file" => Command::OutputFile(argument(1)?),
+        "compare-to" => Command::CompareTo(argument(1)?),
+        "output-list" => Command::OutputList(words[1..].iter().map(|word| word.to_string()).collect()),
+        "set" => Command::Set(argument(1)?, argument(2)?),
+        "ticktock" | "tock" => Command::TickTock,
+        "tick" => Command::Nothing,
+        "output" => Command::Output,
+        "echo" => Command::Echo(argument(1)?.trim_start_matches('"').to_string()),
+        "clear-echo" => Command::Nothing,
+        "repeat" | "while" => {
+            if tokens.get(*position).map(|token| token.as_str()) != Some("{") {
+                return Err(format!("Missing {{ after {}", words[0]));
+            }
+            *position += 1;
+            let body = parse_block(tokens, position, true)?;
+
+            if words[0] == "repeat" {
+                let count = argument(1)?.parse().map_err(|_| "Invalid repeat count".to_string())?;
+                return Ok(Command::Repeat(count, body));
+            }
+            return Ok(Command::While(parse_condition(&words[1..].concat())?, body));
+        }
+        word => return Err(format!("Unknown command: {}", word)),
+    };
+
+    if *position < tokens.len() && tokens[*position] == "{" {
+        return Err(format!("Unexpected {{ after {}", words[0]));
+    }
+    Ok(command)
+}
+
+fn parse_condition(condition: &str) -> Result<Condition, String> {
+    let start = condition.find(['<', '>', '=']).ok_or_else(|| format!("Invalid condition: {}", condition))?;
+    let operator = ["<>", "<=", ">=", "=", "<", ">"]
+        .iter()
+        .find(|operator| condition[start..].starts_with(*operator))
+        .unwrap();
+
+    Ok(Condition {
+        left: condition[..start].to_string(),
+        operator: operator.to_string(),
+        right: condition[start + operator.len()..].to_string(),
+    })
+}
@@ -0,0 +1,41 @@
+use std::io;
+use std::io::Write;
+
+use cpu::{Cpu, Step};
+use disassembler::disassemble;
+
+pub struct Tracer<W: Write> {
+    out: W,
+    // Half-open ROM ranges to trace, everything when empty
+    ranges: Vec<(u16, u16)>,
+}
+
+impl<W: Write> Tracer<W> {
+    pub fn new(out: W, ranges: Vec<(u16, u16)>) -> Self {
+        Tracer { out, ranges }
+    }
+
+    pub fn record(&mut self, step: &Step, cpu: &Cpu) -> io::Result<()> {
+        if !self.ranges.is_empty() &&
+           !self.ranges.iter().any(|&(start, end)| step.pc >= start && step.pc < end) {
+            return Ok(());
+        }
+
+        write!(self.out,
+               "{:>10} {:>5}  {:016b}  {:<12} A={:<6} D={:<6}",
+               cpu.cycles,
+               step.pc,
+               step.instruction,
+               disassemble(step.instruction),
+               cpu.a,
+               cpu.d as i16)?;
+        if let Some(ref write) = step.write {
+            write!(self.out, " RAM[{}]={}", write.address, write.value as i16)?;
+        }
+        writeln!(self.out)
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.out.flush()
+    }
+}
@@ -0,0 +1,219 @@
+use std::io;
+use std::io::Write;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use termion;
+use termion::cursor;
+use termion::clear;
+use termion::event::Key;
+use termion::input::TermRead;
+use termion::raw::IntoRawMode;
+use termion::screen::IntoAlternateScreen;
+
+use cpu::{Cpu, KBD};
+use disassembler::disassemble;
+use display;
+use display::Glyphs;
+use watch::Breaks;
+
+const FRAME: Duration = Duration::from_millis(33);
+// Rows below the screen: registers, disassembly and RAM, plus a status line
+const PANEL_ROWS: usize = 11;
+
+pub struct TuiOptions {
+    pub glyphs: Glyphs,
+    // Pixels per dot, picked to fit the terminal when None
+    pub scale: Option<usize>,
+    // Terminals only report key presses, so a key stays down this long
+    pub key_hold: Duration,
+    // First RAM address shown, follows the stack when None
+    pub ram_view: Option<u16>,
+    // Instructions per second, as fast as possible when None
+    pub speed: Option<u64>,
+}
+
+enum State {
+    Running,
+    Paused,
+    Break(String),
+}
+
+pub fn run(mut cpu: Cpu, breaks: &Breaks, labels: &[(String, u16)], options: &TuiOptions) -> io::Result<()> {
+    let (columns, rows) = termion::terminal_size()?;
+    let scale = options.scale.unwrap_or_else(|| {
+        [1, 2, 4, 8]
+            .iter()
+            .cloned()
+            .find(|&scale| {
+                let (width, height) = options.glyphs.size(scale);
+                width <= columns as usize && height + PANEL_ROWS <= rows as usize
+            })
+            .unwrap_or(8)
+    });
+
+    let stdout = io::stdout().into_raw_mode()?;
+    let mut out = io::BufWriter::new(stdout.into_alternate_screen()?);
+    write!(out, "{}{}", clear::All, cursor::Hide)?;
+
+    let mut keys = termion::async_stdin().keys();
+    let mut release_at = None;
+    let mut state = State::Running;
+    let mut previous_frame: Vec<String> = Vec::new();
+    // Thousandths of an instruction --speed owes from earlier frames, so
+    // speeds under one instruction a frame still make progress
+    let mut owed = 0;
+
+    loop {
+        let frame_start = Instant::now();
+        let mut single_step = false;
+
+        for key in keys.by_ref() {
+            match key? {
+                Key::Ctrl('c') => {
+                    write!(out, "{}", cursor::Show)?;
+                    return out.flush();
+                }
+                Key::Ctrl('p') => {
+                    state = match state {
+                        State::Running => State::Paused,
+                        _ => State::Running,
+                    };
+                }
+                Key::Ctrl('n') => single_step = true,
+                key => {
+                    if let Some(code) = key_code(key) {
+                        cpu.ram[KBD as usize] = code;
+                        release_at = Some(frame_start + options.key_hold);
+                    }
+                }
+            }
+        }
+        if release_at.is_some_and(|release_at| frame_start >= release_at) {
+            cpu.ram[KBD as usize] = 0;
+            release_at = None;
+        }
+
+        let budget = match state {
+            State::Running => {
+                options.speed.map_or(u64::MAX, |speed| {
+                    owed += speed * FRAME.as_millis() as u64;
+                    let budget = owed / 1000;
+                    owed %= 1000;
+                    budget
+                })
+            }
+            _ if single_step => 1,
+            _ => 0,
+        };
+        let mut executed = 0;
+        while executed < budget && !cpu.halted() {
+            let step = cpu.step();
+            executed += 1;
+
+            if let Some(reason) = breaks.check(&step, &cpu) {
+                state = State::Break(reason);
+                break;
+            }
+            // Checking the clock is slow, so only do it every so often
+            if executed % 4096 == 0 && frame_start.elapsed() >= FRAME {
+                break;
+            }
+        }
+
+        let frame = draw(&cpu, &state, labels, options, scale);
+        for (row, line) in frame.iter().enumerate() {
+            if previous_frame.get(row) != Some(line) {
+                write!(out, "{}{}{}", cursor::Goto(1, row as u16 + 1), line, clear::UntilNewline)?;
+            }
+        }
+        out.flush()?;
+        previous_frame = frame;
+
+        let elapsed = frame_start.elapsed();
+        if elapsed < FRAME {
+            thread::sleep(FRAME - elapsed);
+        }
+    }
+}
+
+// Hack keyboard codes, see the Jack OS API
+fn key_code(key: Key) -> Option<u16> {
+    match key {
+        Key::Char('\n') => Some(128),
+        Key::Char(c) if (c as u32) < 128 => Some(c as u16),
+        Key::Backspace => Some(129),
+        Key::Left => Some(130),
+        Key::Up => Some(131),
+        Key::Right => Some(132),
+        Key::Down => Some(133),
+        Key::Home => Some(134),
+        Key::End => Some(135),
+        Key::PageUp => Some(136),
+        Key::PageDown => Some(137),
+        Key::Insert => Some(138),
+        Key::Delete => Some(139),
+        Key::Esc => Some(140),
+        Key::F(n) if (1..=12).contains(&n) => Some(140 + n as u16),
+        _ => None,
+    }
+}
+
+fn draw(cpu: &Cpu, state: &State, labels: &[(String, u16)], options: &TuiOptions, scale: usize) -> Vec<String> {
+    let mut frame = display::render(&cpu.ram, &options.glyphs, scale);
+
+    let registers = vec![
+        format!("PC    {}", cpu.pc),
+        format!("A     {}", cpu.a),
+        format!("D     {}", cpu.d as i16),
+        format!("cycle {}", cpu.cycles),
+        String::new(),
+        format!("SP    {}", cpu.ram[0]),
+        format!("LCL   {}", cpu.ram[1]),
+        format!("ARG   {}", cpu.ram[2]),
+        format!("THIS  {}", cpu.ram[3]),
+        format!("THAT  {}", cpu.ram[4]),
+    ];
+
+    let first = cpu.pc.saturating_sub(4);
+    let disassembly: Vec<String> = (first..first.saturating_add(10))
+        .map(|address| {
+            let instruction = cpu.rom.get(address as usize).cloned().unwrap_or(0);
+            let label = labels
+                .iter()
+                .find(|&&(_, label_address)| label_address == address)
+                .map_or("", |(label, _)| label.as_str());
+            format!("{}{:>5}  {:<12} {}",
+                    if address == cpu.pc { ">" } else { " " },
+                    address,
+                    disassemble(instruction),
+                    label)
+        })
+        .collect();
+
+    let ram_start = options.ram_view.unwrap_or_else(|| cpu.ram[0].saturating_sub(9)).min(32758);
+    let ram: Vec<String> = (ram_start..ram_start + 10)
+        .map(|address| {
+            format!("{}RAM[{}] {}",
+                    if address == cpu.ram[0] { ">" } else { " " },
+                    address,
+                    cpu.ram[address as usize] as i16)
+        })
+        .collect();
+
+    for row in 0..registers.len() {
+        frame.push(format!("{:<16}{:<44}{}", registers[row], disassembly[row], ram[row]));
+    }
+
+    let status = match *state {
+        State::Running if cpu.halted() => "halted".to_string(),
+        State::Running => "running".to_string(),
+        State::Paused => "paused".to_string(),
+        State::Break(ref reason) => format!("break: {}", reason),
+    };
+    frame.push(format!("{}  KBD={}  [Ctrl-P pause  Ctrl-N step  Ctrl-C quit]",
+                       status,
+                       cpu.ram[KBD as usize]));
+
+    frame
+}
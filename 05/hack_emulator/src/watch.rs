@@ -0,0 +1,125 @@
+use cpu::{Cpu, Step, KBD, SCREEN};
+use program::Program;
+
+const SP: usize = 0;
+const ARG: usize = 2;
+const STACK_BASE: u16 = 256;
+const HEAP_BASE: u16 = 2048;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Access {
+    Read,
+    Write,
+    ReadWrite,
+}
+
+pub struct Watchpoint {
+    // Half-open RAM range
+    pub start: u16,
+    pub end: u16,
+    pub access: Access,
+}
+
+impl Watchpoint {
+    fn check(&self, step: &Step) -> Option<String> {
+        let watched = |address: u16| address >= self.start && address < self.end;
+
+        if self.access != Access::Write {
+            if let Some(address) = step.read.filter(|&address| watched(address)) {
+                return Some(format!("read of RAM[{}]", address));
+            }
+        }
+        if self.access != Access::Read {
+            if let Some(write) = step.write.filter(|write| watched(write.address)) {
+                return Some(format!("write of RAM[{}] = {} (was {})",
+                                    write.address,
+                                    write.value as i16,
+                                    write.previous as i16));
+            }
+        }
+        None
+    }
+}
+
+/// Checks the memory discipline that translated VM code has to follow.
+pub struct VmChecker {
+    // Function labels sorted by address
+    functions: Vec<(u16, String)>,
+    // Classes whose functions may draw on the screen
+    screen_owners: Vec<String>,
+}
+
+impl VmChecker {
+    pub fn new(program: &Program, screen_owners: Vec<String>) -> Self {
+        VmChecker {
+            functions: program.functions(),
+            screen_owners,
+        }
+    }
+
+    fn check(&self, step: &Step, cpu: &Cpu) -> Option<String> {
+        let write = step.write?;
+        let address = write.address;
+
+        if address as usize == SP && (write.value < STACK_BASE || write.value >= HEAP_BASE) {
+            return Some(format!("SP set to {}, outside the stack {}-{}",
+                                write.value,
+                                STACK_BASE,
+                                HEAP_BASE - 1));
+        }
+
+        // Below SP only the current frame's arguments, locals and working
+        // stack are live, everything under ARG belongs to the callers
+        let (sp, arg) = (cpu.ram[SP], cpu.ram[ARG]);
+        if (STACK_BASE..HEAP_BASE).contains(&address) && address < sp && address < arg {
+            return Some(format!("write to RAM[{}] below the current frame (SP={}, ARG={})",
+                                address,
+                                sp,
+                                arg));
+        }
+
+        if address >= KBD {
+            return Some(format!("write to RAM[{}], past the end of memory", address));
+        }
+
+        if address >= SCREEN {
+            let function = self.function_at(step.pc);
+            let class = function.and_then(|function| function.split('.').next()).unwrap_or("");
+            if !self.screen_owners.iter().any(|owner| owner == class) {
+                return Some(format!("screen write to RAM[{}] from {}",
+                                    address,
+                                    function.unwrap_or("outside any function")));
+            }
+        }
+
+        None
+    }
+
+    fn function_at(&self, pc: u16) -> Option<&str> {
+        let index = match self.functions.binary_search_by_key(&pc, |&(address, _)| address) {
+            Ok(index) => index,
+            Err(0) => return None,
+            Err(index) => index - 1,
+        };
+        Some(&self.functions[index].1)
+    }
+}
+
+/// Everything that can stop a running program besides halting.
+#[derive(Default)]
+pub struct Breaks {
+    pub watchpoints: Vec<Watchpoint>,
+    pub checker: Option<VmChecker>,
+}
+
+impl Breaks {
+    /// Describes why the program should stop after `step`, if it should.
+    pub fn check(&self, step: &Step, cpu: &Cpu) -> Option<String> {
+        self.watchpoints
+            .iter()
+            .filter_map(|watchpoint| watchpoint.check(step))
+            .next()
+            .or_else(|| self.checker.as_ref().and_then(|checker| checker.check(step, cpu)))
+            .map(|reason| format!("{} at PC={}", reason, step.pc))
+    }
+}
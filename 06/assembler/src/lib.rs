@@ -0,0 +1,140 @@
+pub mod parser;
+use parser::Parser;
+use parser::CommandType;
+
+pub mod code;
+use code::Code;
+
+pub mod symbol_table;
+use symbol_table::SymbolTable;
+
+pub fn predefined_symbol_table<'a>() -> SymbolTable<'a>{
+    let mut symbol_table = SymbolTable::new();
+
+    symbol_table.add_entry("SP", 0);
+    symbol_table.add_entry("LCL", 1);
+    symbol_table.add_entry("ARG", 2);
+    symbol_table.add_entry("THIS", 3);
+    symbol_table.add_entry("THAT", 4);
+    symbol_table.add_entry("R0", 0);
+    symbol_table.add_entry("R1", 1);
+    symbol_table.add_entry("R2", 2);
+    symbol_table.add_entry("R3", 3);
+    symbol_table.add_entry("R4", 4);
+    symbol_table.add_entry("R5", 5);
+    symbol_table.add_entry("R6", 6);
+    symbol_table.add_entry("R7", 7);
+    symbol_table.add_entry("R8", 8);
+    symbol_table.add_entry("R9", 9);
+    symbol_table.add_entry("R10", 10);
+    symbol_table.add_entry("R11", 11);
+    symbol_table.add_entry("R12", 12);
+    symbol_table.add_entry("R13", 13);
+    symbol_table.add_entry("R14", 14);
+    symbol_table.add_entry("R15", 15);
+    symbol_table.add_entry("SCREEN", 16384);
+    symbol_table.add_entry("KBD", 24576);
+
+    symbol_table
+}
+
+/// Returns every label declaration in the program together with the ROM
+/// address it refers to, in source order.
+pub fn rom_labels(buffer: &str) -> Vec<(&str, u16)> {
+    let mut parser = Parser::new(buffer);
+    let mut labels = Vec::new();
+    let mut line_number: u16 = 0;
+
+    while parser.has_more_commands() {
+        parser.advance();
+        if parser.command_type() == CommandType::LCommand {
+            labels.push((parser.symbol(), line_number));
+        } else {
+            line_number += 1;
+        }
+    }
+
+    labels
+}
+
+/// Returns every variable in the program together with the RAM address
+/// `assemble` gives it, in order of first use.
+pub fn variables(buffer: &str) -> Vec<(&str, u16)> {
+    let symbol_table = parse_labels(buffer);
+    let mut parser = Parser::new(buffer);
+    let mut variables: Vec<(&str, u16)> = Vec::new();
+
+    while parser.has_more_commands() {
+        parser.advance();
+        if parser.command_type() == CommandType::ACommand {
+            let symbol = parser.symbol();
+            if symbol.parse::<u16>().is_err() && !symbol_table.contains(symbol) &&
+               !variables.iter().any(|&(variable, _)| variable == symbol) {
+                let address = 16 + variables.len() as u16;
+                variables.push((symbol, address));
+            }
+        }
+    }
+
+    variables
+}
+
+/// Returns the source line of the instruction at every ROM address.
+pub fn listing(buffer: &str) -> Vec<usize> {
+    let mut parser = Parser::new(buffer);
+    let mut lines = Vec::new();
+
+    while parser.has_more_commands() {
+        parser.advance();
+        if parser.command_type() != CommandType::LCommand {
+            lines.push(parser.line_number());
+        }
+    }
+
+    lines
+}
+
+pub fn parse_labels(buffer: &str) -> SymbolTable<'_> {
+    let mut symbol_table = predefined_symbol_table();
+
+    for (label, address) in rom_labels(buffer) {
+        symbol_table.add_entry(label, address);
+    }
+
+    symbol_table
+}
+
+pub fn assemble<'a>(buffer:&'a str, mut symbol_table:SymbolTable<'a>) -> String {
+    let mut parser = Parser::new(buffer);
+    let mut prog = String::new();
+    let mut next_address: u16 = 16;
+
+    while parser.has_more_commands() {
+        parser.advance();
+
+        let opcode = match parser.command_type() {
+            CommandType::ACommand => {
+                let loc:u16 = match parser.symbol().parse::<u16>() {
+                    Ok(v) => v,
+                    _ => {
+                        if !symbol_table.contains(parser.symbol()) {
+                            symbol_table.add_entry(parser.symbol(), next_address);
+                            next_address += 1;
+                        }
+                        symbol_table.get_address(parser.symbol())
+                    }
+                };
+                format!("{:016b}\n", loc)
+            },
+            CommandType::CCommand => {
+                let comp = Code::comp(parser.comp());
+                let dest = Code::dest(parser.dest());
+                let jump = Code::jump(parser.jump());
+                format!("111{}{}{}\n", comp, dest, jump)
+            },
+            _ => String::new()
+        };
+        prog.push_str(&opcode);
+    }
+    prog
+}
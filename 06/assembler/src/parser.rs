@@ -1,7 +1,8 @@
 pub struct Parser<'a> {
     buffer: &'a str,
     remaining: &'a str,
-    current: &'a str
+    current: &'a str,
+    line_number: usize
 }
 
 #[derive(Debug, PartialEq)]
@@ -14,9 +15,10 @@ pub enum CommandType {
 impl<'a> Parser<'a> {
     pub fn new(buffer: &'a str) -> Self {
         Parser {
-            buffer: buffer,
+            buffer,
             remaining: buffer,
-            current: ""
+            current: "",
+            line_number: 0
         }
     }
 
@@ -27,16 +29,22 @@ impl<'a> Parser<'a> {
         })
     }
 
-    pub fn advance(&mut self) -> () {
+    pub fn advance(&mut self) {
         self.current = "";
         while self.current.is_empty() || self.current.starts_with("//") {
             let mut lines = self.remaining.splitn(2, '\n');
             self.current = lines.next().unwrap();
-            self.current = self.current.splitn(2, '/').next().unwrap().trim();
+            self.current = self.current.split('/').next().unwrap().trim();
             self.remaining = lines.next().unwrap_or("");
+            self.line_number += 1;
         }
     }
 
+    /// 1-based source line of the current command
+    pub fn line_number(&self) -> usize {
+        self.line_number
+    }
+
     pub fn command_type(&self) -> CommandType {
         if self.current.starts_with("@") {
             CommandType::ACommand
@@ -51,8 +59,8 @@ impl<'a> Parser<'a> {
         match self.command_type() {
             CommandType::ACommand => &self.current[1..],
             CommandType::LCommand => self.current
-                .trim_left_matches('(')
-                .trim_right_matches(')'),
+                .trim_start_matches('(')
+                .trim_end_matches(')'),
             _ => panic!()
         }
     }
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+#[derive(Default)]
 pub struct SymbolTable<'a> {
     hash_map: HashMap<&'a str, u16>
 }
@@ -11,7 +12,7 @@ impl<'a> SymbolTable<'a> {
         }
     }
 
-    pub fn add_entry(&mut self, symbol: &'a str, address: u16) -> () {
+    pub fn add_entry(&mut self, symbol: &'a str, address: u16) {
         self.hash_map.insert(symbol, address);
     }
 
@@ -20,6 +21,6 @@ impl<'a> SymbolTable<'a> {
     }
 
     pub fn get_address(&self, symbol: &str) -> u16 {
-        self.hash_map.get(symbol).expect("Unable to find symbol").clone()
+        *self.hash_map.get(symbol).expect("Unable to find symbol")
     }
 }
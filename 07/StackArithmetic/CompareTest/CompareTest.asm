@@ -0,0 +1,7808 @@
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE0_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE0_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE0_END 
+0;JMP 
+(COMPARE0_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE0_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE0_END 
+0;JMP 
+(COMPARE0_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE0_TRUE 
+D;JLT 
+D=0 
+@COMPARE0_END 
+0;JMP 
+(COMPARE0_TRUE) 
+D=-1 
+(COMPARE0_END) 
+@SP 
+A=M-1 
+M=D 
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE1_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE1_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE1_END 
+0;JMP 
+(COMPARE1_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE1_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE1_END 
+0;JMP 
+(COMPARE1_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE1_TRUE 
+D;JGT 
+D=0 
+@COMPARE1_END 
+0;JMP 
+(COMPARE1_TRUE) 
+D=-1 
+(COMPARE1_END) 
+@SP 
+A=M-1 
+M=D 
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE2 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE2 
+0;JMP 
+(FALSE2) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE2)
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE3_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE3_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE3_END 
+0;JMP 
+(COMPARE3_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE3_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE3_END 
+0;JMP 
+(COMPARE3_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE3_TRUE 
+D;JLT 
+D=0 
+@COMPARE3_END 
+0;JMP 
+(COMPARE3_TRUE) 
+D=-1 
+(COMPARE3_END) 
+@SP 
+A=M-1 
+M=D 
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE4_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE4_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE4_END 
+0;JMP 
+(COMPARE4_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE4_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE4_END 
+0;JMP 
+(COMPARE4_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE4_TRUE 
+D;JGT 
+D=0 
+@COMPARE4_END 
+0;JMP 
+(COMPARE4_TRUE) 
+D=-1 
+(COMPARE4_END) 
+@SP 
+A=M-1 
+M=D 
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE5 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE5 
+0;JMP 
+(FALSE5) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE5)
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE6_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE6_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE6_END 
+0;JMP 
+(COMPARE6_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE6_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE6_END 
+0;JMP 
+(COMPARE6_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE6_TRUE 
+D;JLT 
+D=0 
+@COMPARE6_END 
+0;JMP 
+(COMPARE6_TRUE) 
+D=-1 
+(COMPARE6_END) 
+@SP 
+A=M-1 
+M=D 
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE7_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE7_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE7_END 
+0;JMP 
+(COMPARE7_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE7_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE7_END 
+0;JMP 
+(COMPARE7_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE7_TRUE 
+D;JGT 
+D=0 
+@COMPARE7_END 
+0;JMP 
+(COMPARE7_TRUE) 
+D=-1 
+(COMPARE7_END) 
+@SP 
+A=M-1 
+M=D 
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE8 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE8 
+0;JMP 
+(FALSE8) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE8)
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE9_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE9_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE9_END 
+0;JMP 
+(COMPARE9_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE9_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE9_END 
+0;JMP 
+(COMPARE9_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE9_TRUE 
+D;JLT 
+D=0 
+@COMPARE9_END 
+0;JMP 
+(COMPARE9_TRUE) 
+D=-1 
+(COMPARE9_END) 
+@SP 
+A=M-1 
+M=D 
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE10_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE10_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE10_END 
+0;JMP 
+(COMPARE10_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE10_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE10_END 
+0;JMP 
+(COMPARE10_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE10_TRUE 
+D;JGT 
+D=0 
+@COMPARE10_END 
+0;JMP 
+(COMPARE10_TRUE) 
+D=-1 
+(COMPARE10_END) 
+@SP 
+A=M-1 
+M=D 
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE11 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE11 
+0;JMP 
+(FALSE11) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE11)
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE12_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE12_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE12_END 
+0;JMP 
+(COMPARE12_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE12_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE12_END 
+0;JMP 
+(COMPARE12_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE12_TRUE 
+D;JLT 
+D=0 
+@COMPARE12_END 
+0;JMP 
+(COMPARE12_TRUE) 
+D=-1 
+(COMPARE12_END) 
+@SP 
+A=M-1 
+M=D 
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE13_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE13_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE13_END 
+0;JMP 
+(COMPARE13_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE13_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE13_END 
+0;JMP 
+(COMPARE13_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE13_TRUE 
+D;JGT 
+D=0 
+@COMPARE13_END 
+0;JMP 
+(COMPARE13_TRUE) 
+D=-1 
+(COMPARE13_END) 
+@SP 
+A=M-1 
+M=D 
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE14 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE14 
+0;JMP 
+(FALSE14) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE14)
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE15_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE15_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE15_END 
+0;JMP 
+(COMPARE15_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE15_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE15_END 
+0;JMP 
+(COMPARE15_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE15_TRUE 
+D;JLT 
+D=0 
+@COMPARE15_END 
+0;JMP 
+(COMPARE15_TRUE) 
+D=-1 
+(COMPARE15_END) 
+@SP 
+A=M-1 
+M=D 
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE16_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE16_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE16_END 
+0;JMP 
+(COMPARE16_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE16_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE16_END 
+0;JMP 
+(COMPARE16_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE16_TRUE 
+D;JGT 
+D=0 
+@COMPARE16_END 
+0;JMP 
+(COMPARE16_TRUE) 
+D=-1 
+(COMPARE16_END) 
+@SP 
+A=M-1 
+M=D 
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE17 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE17 
+0;JMP 
+(FALSE17) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE17)
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE18_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE18_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE18_END 
+0;JMP 
+(COMPARE18_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE18_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE18_END 
+0;JMP 
+(COMPARE18_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE18_TRUE 
+D;JLT 
+D=0 
+@COMPARE18_END 
+0;JMP 
+(COMPARE18_TRUE) 
+D=-1 
+(COMPARE18_END) 
+@SP 
+A=M-1 
+M=D 
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE19_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE19_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE19_END 
+0;JMP 
+(COMPARE19_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE19_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE19_END 
+0;JMP 
+(COMPARE19_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE19_TRUE 
+D;JGT 
+D=0 
+@COMPARE19_END 
+0;JMP 
+(COMPARE19_TRUE) 
+D=-1 
+(COMPARE19_END) 
+@SP 
+A=M-1 
+M=D 
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE20 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE20 
+0;JMP 
+(FALSE20) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE20)
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE21_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE21_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE21_END 
+0;JMP 
+(COMPARE21_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE21_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE21_END 
+0;JMP 
+(COMPARE21_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE21_TRUE 
+D;JLT 
+D=0 
+@COMPARE21_END 
+0;JMP 
+(COMPARE21_TRUE) 
+D=-1 
+(COMPARE21_END) 
+@SP 
+A=M-1 
+M=D 
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE22_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE22_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE22_END 
+0;JMP 
+(COMPARE22_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE22_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE22_END 
+0;JMP 
+(COMPARE22_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE22_TRUE 
+D;JGT 
+D=0 
+@COMPARE22_END 
+0;JMP 
+(COMPARE22_TRUE) 
+D=-1 
+(COMPARE22_END) 
+@SP 
+A=M-1 
+M=D 
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE23 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE23 
+0;JMP 
+(FALSE23) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE23)
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE24_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE24_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE24_END 
+0;JMP 
+(COMPARE24_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE24_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE24_END 
+0;JMP 
+(COMPARE24_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE24_TRUE 
+D;JLT 
+D=0 
+@COMPARE24_END 
+0;JMP 
+(COMPARE24_TRUE) 
+D=-1 
+(COMPARE24_END) 
+@SP 
+A=M-1 
+M=D 
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE25_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE25_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE25_END 
+0;JMP 
+(COMPARE25_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE25_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE25_END 
+0;JMP 
+(COMPARE25_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE25_TRUE 
+D;JGT 
+D=0 
+@COMPARE25_END 
+0;JMP 
+(COMPARE25_TRUE) 
+D=-1 
+(COMPARE25_END) 
+@SP 
+A=M-1 
+M=D 
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE26 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE26 
+0;JMP 
+(FALSE26) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE26)
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE27_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE27_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE27_END 
+0;JMP 
+(COMPARE27_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE27_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE27_END 
+0;JMP 
+(COMPARE27_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE27_TRUE 
+D;JLT 
+D=0 
+@COMPARE27_END 
+0;JMP 
+(COMPARE27_TRUE) 
+D=-1 
+(COMPARE27_END) 
+@SP 
+A=M-1 
+M=D 
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE28_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE28_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE28_END 
+0;JMP 
+(COMPARE28_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE28_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE28_END 
+0;JMP 
+(COMPARE28_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE28_TRUE 
+D;JGT 
+D=0 
+@COMPARE28_END 
+0;JMP 
+(COMPARE28_TRUE) 
+D=-1 
+(COMPARE28_END) 
+@SP 
+A=M-1 
+M=D 
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE29 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE29 
+0;JMP 
+(FALSE29) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE29)
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE30_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE30_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE30_END 
+0;JMP 
+(COMPARE30_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE30_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE30_END 
+0;JMP 
+(COMPARE30_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE30_TRUE 
+D;JLT 
+D=0 
+@COMPARE30_END 
+0;JMP 
+(COMPARE30_TRUE) 
+D=-1 
+(COMPARE30_END) 
+@SP 
+A=M-1 
+M=D 
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE31_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE31_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE31_END 
+0;JMP 
+(COMPARE31_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE31_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE31_END 
+0;JMP 
+(COMPARE31_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE31_TRUE 
+D;JGT 
+D=0 
+@COMPARE31_END 
+0;JMP 
+(COMPARE31_TRUE) 
+D=-1 
+(COMPARE31_END) 
+@SP 
+A=M-1 
+M=D 
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE32 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE32 
+0;JMP 
+(FALSE32) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE32)
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE33_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE33_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE33_END 
+0;JMP 
+(COMPARE33_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE33_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE33_END 
+0;JMP 
+(COMPARE33_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE33_TRUE 
+D;JLT 
+D=0 
+@COMPARE33_END 
+0;JMP 
+(COMPARE33_TRUE) 
+D=-1 
+(COMPARE33_END) 
+@SP 
+A=M-1 
+M=D 
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE34_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE34_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE34_END 
+0;JMP 
+(COMPARE34_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE34_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE34_END 
+0;JMP 
+(COMPARE34_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE34_TRUE 
+D;JGT 
+D=0 
+@COMPARE34_END 
+0;JMP 
+(COMPARE34_TRUE) 
+D=-1 
+(COMPARE34_END) 
+@SP 
+A=M-1 
+M=D 
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE35 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE35 
+0;JMP 
+(FALSE35) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE35)
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE36_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE36_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE36_END 
+0;JMP 
+(COMPARE36_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE36_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE36_END 
+0;JMP 
+(COMPARE36_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE36_TRUE 
+D;JLT 
+D=0 
+@COMPARE36_END 
+0;JMP 
+(COMPARE36_TRUE) 
+D=-1 
+(COMPARE36_END) 
+@SP 
+A=M-1 
+M=D 
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE37_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE37_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE37_END 
+0;JMP 
+(COMPARE37_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE37_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE37_END 
+0;JMP 
+(COMPARE37_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE37_TRUE 
+D;JGT 
+D=0 
+@COMPARE37_END 
+0;JMP 
+(COMPARE37_TRUE) 
+D=-1 
+(COMPARE37_END) 
+@SP 
+A=M-1 
+M=D 
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE38 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE38 
+0;JMP 
+(FALSE38) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE38)
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE39_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE39_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE39_END 
+0;JMP 
+(COMPARE39_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE39_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE39_END 
+0;JMP 
+(COMPARE39_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE39_TRUE 
+D;JLT 
+D=0 
+@COMPARE39_END 
+0;JMP 
+(COMPARE39_TRUE) 
+D=-1 
+(COMPARE39_END) 
+@SP 
+A=M-1 
+M=D 
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE40_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE40_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE40_END 
+0;JMP 
+(COMPARE40_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE40_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE40_END 
+0;JMP 
+(COMPARE40_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE40_TRUE 
+D;JGT 
+D=0 
+@COMPARE40_END 
+0;JMP 
+(COMPARE40_TRUE) 
+D=-1 
+(COMPARE40_END) 
+@SP 
+A=M-1 
+M=D 
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE41 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE41 
+0;JMP 
+(FALSE41) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE41)
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE42_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE42_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE42_END 
+0;JMP 
+(COMPARE42_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE42_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE42_END 
+0;JMP 
+(COMPARE42_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE42_TRUE 
+D;JLT 
+D=0 
+@COMPARE42_END 
+0;JMP 
+(COMPARE42_TRUE) 
+D=-1 
+(COMPARE42_END) 
+@SP 
+A=M-1 
+M=D 
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE43_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE43_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE43_END 
+0;JMP 
+(COMPARE43_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE43_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE43_END 
+0;JMP 
+(COMPARE43_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE43_TRUE 
+D;JGT 
+D=0 
+@COMPARE43_END 
+0;JMP 
+(COMPARE43_TRUE) 
+D=-1 
+(COMPARE43_END) 
+@SP 
+A=M-1 
+M=D 
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE44 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE44 
+0;JMP 
+(FALSE44) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE44)
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE45_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE45_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE45_END 
+0;JMP 
+(COMPARE45_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE45_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE45_END 
+0;JMP 
+(COMPARE45_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE45_TRUE 
+D;JLT 
+D=0 
+@COMPARE45_END 
+0;JMP 
+(COMPARE45_TRUE) 
+D=-1 
+(COMPARE45_END) 
+@SP 
+A=M-1 
+M=D 
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE46_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE46_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE46_END 
+0;JMP 
+(COMPARE46_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE46_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE46_END 
+0;JMP 
+(COMPARE46_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE46_TRUE 
+D;JGT 
+D=0 
+@COMPARE46_END 
+0;JMP 
+(COMPARE46_TRUE) 
+D=-1 
+(COMPARE46_END) 
+@SP 
+A=M-1 
+M=D 
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE47 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE47 
+0;JMP 
+(FALSE47) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE47)
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE48_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE48_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE48_END 
+0;JMP 
+(COMPARE48_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE48_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE48_END 
+0;JMP 
+(COMPARE48_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE48_TRUE 
+D;JLT 
+D=0 
+@COMPARE48_END 
+0;JMP 
+(COMPARE48_TRUE) 
+D=-1 
+(COMPARE48_END) 
+@SP 
+A=M-1 
+M=D 
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE49_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE49_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE49_END 
+0;JMP 
+(COMPARE49_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE49_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE49_END 
+0;JMP 
+(COMPARE49_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE49_TRUE 
+D;JGT 
+D=0 
+@COMPARE49_END 
+0;JMP 
+(COMPARE49_TRUE) 
+D=-1 
+(COMPARE49_END) 
+@SP 
+A=M-1 
+M=D 
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE50 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE50 
+0;JMP 
+(FALSE50) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE50)
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE51_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE51_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE51_END 
+0;JMP 
+(COMPARE51_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE51_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE51_END 
+0;JMP 
+(COMPARE51_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE51_TRUE 
+D;JLT 
+D=0 
+@COMPARE51_END 
+0;JMP 
+(COMPARE51_TRUE) 
+D=-1 
+(COMPARE51_END) 
+@SP 
+A=M-1 
+M=D 
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE52_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE52_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE52_END 
+0;JMP 
+(COMPARE52_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE52_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE52_END 
+0;JMP 
+(COMPARE52_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE52_TRUE 
+D;JGT 
+D=0 
+@COMPARE52_END 
+0;JMP 
+(COMPARE52_TRUE) 
+D=-1 
+(COMPARE52_END) 
+@SP 
+A=M-1 
+M=D 
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE53 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE53 
+0;JMP 
+(FALSE53) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE53)
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE54_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE54_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE54_END 
+0;JMP 
+(COMPARE54_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE54_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE54_END 
+0;JMP 
+(COMPARE54_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE54_TRUE 
+D;JLT 
+D=0 
+@COMPARE54_END 
+0;JMP 
+(COMPARE54_TRUE) 
+D=-1 
+(COMPARE54_END) 
+@SP 
+A=M-1 
+M=D 
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE55_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE55_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE55_END 
+0;JMP 
+(COMPARE55_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE55_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE55_END 
+0;JMP 
+(COMPARE55_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE55_TRUE 
+D;JGT 
+D=0 
+@COMPARE55_END 
+0;JMP 
+(COMPARE55_TRUE) 
+D=-1 
+(COMPARE55_END) 
+@SP 
+A=M-1 
+M=D 
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE56 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE56 
+0;JMP 
+(FALSE56) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE56)
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE57_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE57_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE57_END 
+0;JMP 
+(COMPARE57_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE57_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE57_END 
+0;JMP 
+(COMPARE57_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE57_TRUE 
+D;JLT 
+D=0 
+@COMPARE57_END 
+0;JMP 
+(COMPARE57_TRUE) 
+D=-1 
+(COMPARE57_END) 
+@SP 
+A=M-1 
+M=D 
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE58_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE58_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE58_END 
+0;JMP 
+(COMPARE58_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE58_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE58_END 
+0;JMP 
+(COMPARE58_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE58_TRUE 
+D;JGT 
+D=0 
+@COMPARE58_END 
+0;JMP 
+(COMPARE58_TRUE) 
+D=-1 
+(COMPARE58_END) 
+@SP 
+A=M-1 
+M=D 
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE59 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE59 
+0;JMP 
+(FALSE59) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE59)
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE60_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE60_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE60_END 
+0;JMP 
+(COMPARE60_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE60_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE60_END 
+0;JMP 
+(COMPARE60_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE60_TRUE 
+D;JLT 
+D=0 
+@COMPARE60_END 
+0;JMP 
+(COMPARE60_TRUE) 
+D=-1 
+(COMPARE60_END) 
+@SP 
+A=M-1 
+M=D 
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE61_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE61_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE61_END 
+0;JMP 
+(COMPARE61_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE61_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE61_END 
+0;JMP 
+(COMPARE61_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE61_TRUE 
+D;JGT 
+D=0 
+@COMPARE61_END 
+0;JMP 
+(COMPARE61_TRUE) 
+D=-1 
+(COMPARE61_END) 
+@SP 
+A=M-1 
+M=D 
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE62 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE62 
+0;JMP 
+(FALSE62) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE62)
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE63_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE63_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE63_END 
+0;JMP 
+(COMPARE63_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE63_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE63_END 
+0;JMP 
+(COMPARE63_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE63_TRUE 
+D;JLT 
+D=0 
+@COMPARE63_END 
+0;JMP 
+(COMPARE63_TRUE) 
+D=-1 
+(COMPARE63_END) 
+@SP 
+A=M-1 
+M=D 
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE64_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE64_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE64_END 
+0;JMP 
+(COMPARE64_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE64_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE64_END 
+0;JMP 
+(COMPARE64_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE64_TRUE 
+D;JGT 
+D=0 
+@COMPARE64_END 
+0;JMP 
+(COMPARE64_TRUE) 
+D=-1 
+(COMPARE64_END) 
+@SP 
+A=M-1 
+M=D 
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE65 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE65 
+0;JMP 
+(FALSE65) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE65)
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE66_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE66_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE66_END 
+0;JMP 
+(COMPARE66_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE66_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE66_END 
+0;JMP 
+(COMPARE66_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE66_TRUE 
+D;JLT 
+D=0 
+@COMPARE66_END 
+0;JMP 
+(COMPARE66_TRUE) 
+D=-1 
+(COMPARE66_END) 
+@SP 
+A=M-1 
+M=D 
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE67_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE67_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE67_END 
+0;JMP 
+(COMPARE67_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE67_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE67_END 
+0;JMP 
+(COMPARE67_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE67_TRUE 
+D;JGT 
+D=0 
+@COMPARE67_END 
+0;JMP 
+(COMPARE67_TRUE) 
+D=-1 
+(COMPARE67_END) 
+@SP 
+A=M-1 
+M=D 
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE68 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE68 
+0;JMP 
+(FALSE68) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE68)
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE69_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE69_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE69_END 
+0;JMP 
+(COMPARE69_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE69_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE69_END 
+0;JMP 
+(COMPARE69_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE69_TRUE 
+D;JLT 
+D=0 
+@COMPARE69_END 
+0;JMP 
+(COMPARE69_TRUE) 
+D=-1 
+(COMPARE69_END) 
+@SP 
+A=M-1 
+M=D 
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE70_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE70_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE70_END 
+0;JMP 
+(COMPARE70_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE70_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE70_END 
+0;JMP 
+(COMPARE70_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE70_TRUE 
+D;JGT 
+D=0 
+@COMPARE70_END 
+0;JMP 
+(COMPARE70_TRUE) 
+D=-1 
+(COMPARE70_END) 
+@SP 
+A=M-1 
+M=D 
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE71 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE71 
+0;JMP 
+(FALSE71) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE71)
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE72_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE72_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE72_END 
+0;JMP 
+(COMPARE72_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE72_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE72_END 
+0;JMP 
+(COMPARE72_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE72_TRUE 
+D;JLT 
+D=0 
+@COMPARE72_END 
+0;JMP 
+(COMPARE72_TRUE) 
+D=-1 
+(COMPARE72_END) 
+@SP 
+A=M-1 
+M=D 
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE73_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE73_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE73_END 
+0;JMP 
+(COMPARE73_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE73_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE73_END 
+0;JMP 
+(COMPARE73_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE73_TRUE 
+D;JGT 
+D=0 
+@COMPARE73_END 
+0;JMP 
+(COMPARE73_TRUE) 
+D=-1 
+(COMPARE73_END) 
+@SP 
+A=M-1 
+M=D 
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE74 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE74 
+0;JMP 
+(FALSE74) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE74)
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE75_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE75_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE75_END 
+0;JMP 
+(COMPARE75_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE75_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE75_END 
+0;JMP 
+(COMPARE75_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE75_TRUE 
+D;JLT 
+D=0 
+@COMPARE75_END 
+0;JMP 
+(COMPARE75_TRUE) 
+D=-1 
+(COMPARE75_END) 
+@SP 
+A=M-1 
+M=D 
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE76_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE76_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE76_END 
+0;JMP 
+(COMPARE76_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE76_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE76_END 
+0;JMP 
+(COMPARE76_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE76_TRUE 
+D;JGT 
+D=0 
+@COMPARE76_END 
+0;JMP 
+(COMPARE76_TRUE) 
+D=-1 
+(COMPARE76_END) 
+@SP 
+A=M-1 
+M=D 
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE77 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE77 
+0;JMP 
+(FALSE77) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE77)
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE78_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE78_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE78_END 
+0;JMP 
+(COMPARE78_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE78_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE78_END 
+0;JMP 
+(COMPARE78_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE78_TRUE 
+D;JLT 
+D=0 
+@COMPARE78_END 
+0;JMP 
+(COMPARE78_TRUE) 
+D=-1 
+(COMPARE78_END) 
+@SP 
+A=M-1 
+M=D 
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE79_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE79_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE79_END 
+0;JMP 
+(COMPARE79_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE79_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE79_END 
+0;JMP 
+(COMPARE79_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE79_TRUE 
+D;JGT 
+D=0 
+@COMPARE79_END 
+0;JMP 
+(COMPARE79_TRUE) 
+D=-1 
+(COMPARE79_END) 
+@SP 
+A=M-1 
+M=D 
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE80 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE80 
+0;JMP 
+(FALSE80) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE80)
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE81_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE81_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE81_END 
+0;JMP 
+(COMPARE81_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE81_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE81_END 
+0;JMP 
+(COMPARE81_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE81_TRUE 
+D;JLT 
+D=0 
+@COMPARE81_END 
+0;JMP 
+(COMPARE81_TRUE) 
+D=-1 
+(COMPARE81_END) 
+@SP 
+A=M-1 
+M=D 
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE82_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE82_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE82_END 
+0;JMP 
+(COMPARE82_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE82_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE82_END 
+0;JMP 
+(COMPARE82_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE82_TRUE 
+D;JGT 
+D=0 
+@COMPARE82_END 
+0;JMP 
+(COMPARE82_TRUE) 
+D=-1 
+(COMPARE82_END) 
+@SP 
+A=M-1 
+M=D 
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE83 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE83 
+0;JMP 
+(FALSE83) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE83)
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE84_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE84_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE84_END 
+0;JMP 
+(COMPARE84_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE84_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE84_END 
+0;JMP 
+(COMPARE84_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE84_TRUE 
+D;JLT 
+D=0 
+@COMPARE84_END 
+0;JMP 
+(COMPARE84_TRUE) 
+D=-1 
+(COMPARE84_END) 
+@SP 
+A=M-1 
+M=D 
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE85_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE85_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE85_END 
+0;JMP 
+(COMPARE85_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE85_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE85_END 
+0;JMP 
+(COMPARE85_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE85_TRUE 
+D;JGT 
+D=0 
+@COMPARE85_END 
+0;JMP 
+(COMPARE85_TRUE) 
+D=-1 
+(COMPARE85_END) 
+@SP 
+A=M-1 
+M=D 
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE86 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE86 
+0;JMP 
+(FALSE86) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE86)
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE87_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE87_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE87_END 
+0;JMP 
+(COMPARE87_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE87_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE87_END 
+0;JMP 
+(COMPARE87_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE87_TRUE 
+D;JLT 
+D=0 
+@COMPARE87_END 
+0;JMP 
+(COMPARE87_TRUE) 
+D=-1 
+(COMPARE87_END) 
+@SP 
+A=M-1 
+M=D 
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE88_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE88_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE88_END 
+0;JMP 
+(COMPARE88_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE88_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE88_END 
+0;JMP 
+(COMPARE88_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE88_TRUE 
+D;JGT 
+D=0 
+@COMPARE88_END 
+0;JMP 
+(COMPARE88_TRUE) 
+D=-1 
+(COMPARE88_END) 
+@SP 
+A=M-1 
+M=D 
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE89 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE89 
+0;JMP 
+(FALSE89) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE89)
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE90_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE90_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE90_END 
+0;JMP 
+(COMPARE90_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE90_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE90_END 
+0;JMP 
+(COMPARE90_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE90_TRUE 
+D;JLT 
+D=0 
+@COMPARE90_END 
+0;JMP 
+(COMPARE90_TRUE) 
+D=-1 
+(COMPARE90_END) 
+@SP 
+A=M-1 
+M=D 
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE91_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE91_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE91_END 
+0;JMP 
+(COMPARE91_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE91_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE91_END 
+0;JMP 
+(COMPARE91_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE91_TRUE 
+D;JGT 
+D=0 
+@COMPARE91_END 
+0;JMP 
+(COMPARE91_TRUE) 
+D=-1 
+(COMPARE91_END) 
+@SP 
+A=M-1 
+M=D 
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE92 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE92 
+0;JMP 
+(FALSE92) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE92)
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE93_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE93_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE93_END 
+0;JMP 
+(COMPARE93_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE93_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE93_END 
+0;JMP 
+(COMPARE93_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE93_TRUE 
+D;JLT 
+D=0 
+@COMPARE93_END 
+0;JMP 
+(COMPARE93_TRUE) 
+D=-1 
+(COMPARE93_END) 
+@SP 
+A=M-1 
+M=D 
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE94_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE94_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE94_END 
+0;JMP 
+(COMPARE94_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE94_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE94_END 
+0;JMP 
+(COMPARE94_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE94_TRUE 
+D;JGT 
+D=0 
+@COMPARE94_END 
+0;JMP 
+(COMPARE94_TRUE) 
+D=-1 
+(COMPARE94_END) 
+@SP 
+A=M-1 
+M=D 
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE95 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE95 
+0;JMP 
+(FALSE95) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE95)
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE96_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE96_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE96_END 
+0;JMP 
+(COMPARE96_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE96_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE96_END 
+0;JMP 
+(COMPARE96_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE96_TRUE 
+D;JLT 
+D=0 
+@COMPARE96_END 
+0;JMP 
+(COMPARE96_TRUE) 
+D=-1 
+(COMPARE96_END) 
+@SP 
+A=M-1 
+M=D 
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE97_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE97_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE97_END 
+0;JMP 
+(COMPARE97_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE97_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE97_END 
+0;JMP 
+(COMPARE97_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE97_TRUE 
+D;JGT 
+D=0 
+@COMPARE97_END 
+0;JMP 
+(COMPARE97_TRUE) 
+D=-1 
+(COMPARE97_END) 
+@SP 
+A=M-1 
+M=D 
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE98 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE98 
+0;JMP 
+(FALSE98) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE98)
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE99_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE99_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE99_END 
+0;JMP 
+(COMPARE99_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE99_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE99_END 
+0;JMP 
+(COMPARE99_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE99_TRUE 
+D;JLT 
+D=0 
+@COMPARE99_END 
+0;JMP 
+(COMPARE99_TRUE) 
+D=-1 
+(COMPARE99_END) 
+@SP 
+A=M-1 
+M=D 
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE100_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE100_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE100_END 
+0;JMP 
+(COMPARE100_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE100_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE100_END 
+0;JMP 
+(COMPARE100_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE100_TRUE 
+D;JGT 
+D=0 
+@COMPARE100_END 
+0;JMP 
+(COMPARE100_TRUE) 
+D=-1 
+(COMPARE100_END) 
+@SP 
+A=M-1 
+M=D 
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE101 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE101 
+0;JMP 
+(FALSE101) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE101)
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE102_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE102_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE102_END 
+0;JMP 
+(COMPARE102_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE102_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE102_END 
+0;JMP 
+(COMPARE102_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE102_TRUE 
+D;JLT 
+D=0 
+@COMPARE102_END 
+0;JMP 
+(COMPARE102_TRUE) 
+D=-1 
+(COMPARE102_END) 
+@SP 
+A=M-1 
+M=D 
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE103_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE103_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE103_END 
+0;JMP 
+(COMPARE103_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE103_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE103_END 
+0;JMP 
+(COMPARE103_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE103_TRUE 
+D;JGT 
+D=0 
+@COMPARE103_END 
+0;JMP 
+(COMPARE103_TRUE) 
+D=-1 
+(COMPARE103_END) 
+@SP 
+A=M-1 
+M=D 
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE104 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE104 
+0;JMP 
+(FALSE104) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE104)
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE105_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE105_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE105_END 
+0;JMP 
+(COMPARE105_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE105_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE105_END 
+0;JMP 
+(COMPARE105_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE105_TRUE 
+D;JLT 
+D=0 
+@COMPARE105_END 
+0;JMP 
+(COMPARE105_TRUE) 
+D=-1 
+(COMPARE105_END) 
+@SP 
+A=M-1 
+M=D 
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE106_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE106_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE106_END 
+0;JMP 
+(COMPARE106_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE106_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE106_END 
+0;JMP 
+(COMPARE106_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE106_TRUE 
+D;JGT 
+D=0 
+@COMPARE106_END 
+0;JMP 
+(COMPARE106_TRUE) 
+D=-1 
+(COMPARE106_END) 
+@SP 
+A=M-1 
+M=D 
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE107 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE107 
+0;JMP 
+(FALSE107) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE107)
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE108_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE108_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE108_END 
+0;JMP 
+(COMPARE108_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE108_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE108_END 
+0;JMP 
+(COMPARE108_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE108_TRUE 
+D;JLT 
+D=0 
+@COMPARE108_END 
+0;JMP 
+(COMPARE108_TRUE) 
+D=-1 
+(COMPARE108_END) 
+@SP 
+A=M-1 
+M=D 
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE109_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE109_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE109_END 
+0;JMP 
+(COMPARE109_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE109_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE109_END 
+0;JMP 
+(COMPARE109_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE109_TRUE 
+D;JGT 
+D=0 
+@COMPARE109_END 
+0;JMP 
+(COMPARE109_TRUE) 
+D=-1 
+(COMPARE109_END) 
+@SP 
+A=M-1 
+M=D 
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE110 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE110 
+0;JMP 
+(FALSE110) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE110)
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE111_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE111_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE111_END 
+0;JMP 
+(COMPARE111_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE111_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE111_END 
+0;JMP 
+(COMPARE111_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE111_TRUE 
+D;JLT 
+D=0 
+@COMPARE111_END 
+0;JMP 
+(COMPARE111_TRUE) 
+D=-1 
+(COMPARE111_END) 
+@SP 
+A=M-1 
+M=D 
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE112_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE112_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE112_END 
+0;JMP 
+(COMPARE112_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE112_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE112_END 
+0;JMP 
+(COMPARE112_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE112_TRUE 
+D;JGT 
+D=0 
+@COMPARE112_END 
+0;JMP 
+(COMPARE112_TRUE) 
+D=-1 
+(COMPARE112_END) 
+@SP 
+A=M-1 
+M=D 
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE113 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE113 
+0;JMP 
+(FALSE113) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE113)
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE114_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE114_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE114_END 
+0;JMP 
+(COMPARE114_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE114_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE114_END 
+0;JMP 
+(COMPARE114_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE114_TRUE 
+D;JLT 
+D=0 
+@COMPARE114_END 
+0;JMP 
+(COMPARE114_TRUE) 
+D=-1 
+(COMPARE114_END) 
+@SP 
+A=M-1 
+M=D 
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE115_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE115_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE115_END 
+0;JMP 
+(COMPARE115_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE115_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE115_END 
+0;JMP 
+(COMPARE115_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE115_TRUE 
+D;JGT 
+D=0 
+@COMPARE115_END 
+0;JMP 
+(COMPARE115_TRUE) 
+D=-1 
+(COMPARE115_END) 
+@SP 
+A=M-1 
+M=D 
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE116 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE116 
+0;JMP 
+(FALSE116) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE116)
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE117_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE117_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE117_END 
+0;JMP 
+(COMPARE117_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE117_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE117_END 
+0;JMP 
+(COMPARE117_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE117_TRUE 
+D;JLT 
+D=0 
+@COMPARE117_END 
+0;JMP 
+(COMPARE117_TRUE) 
+D=-1 
+(COMPARE117_END) 
+@SP 
+A=M-1 
+M=D 
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE118_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE118_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE118_END 
+0;JMP 
+(COMPARE118_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE118_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE118_END 
+0;JMP 
+(COMPARE118_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE118_TRUE 
+D;JGT 
+D=0 
+@COMPARE118_END 
+0;JMP 
+(COMPARE118_TRUE) 
+D=-1 
+(COMPARE118_END) 
+@SP 
+A=M-1 
+M=D 
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE119 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE119 
+0;JMP 
+(FALSE119) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE119)
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE120_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE120_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE120_END 
+0;JMP 
+(COMPARE120_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE120_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE120_END 
+0;JMP 
+(COMPARE120_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE120_TRUE 
+D;JLT 
+D=0 
+@COMPARE120_END 
+0;JMP 
+(COMPARE120_TRUE) 
+D=-1 
+(COMPARE120_END) 
+@SP 
+A=M-1 
+M=D 
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE121_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE121_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE121_END 
+0;JMP 
+(COMPARE121_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE121_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE121_END 
+0;JMP 
+(COMPARE121_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE121_TRUE 
+D;JGT 
+D=0 
+@COMPARE121_END 
+0;JMP 
+(COMPARE121_TRUE) 
+D=-1 
+(COMPARE121_END) 
+@SP 
+A=M-1 
+M=D 
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE122 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE122 
+0;JMP 
+(FALSE122) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE122)
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE123_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE123_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE123_END 
+0;JMP 
+(COMPARE123_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE123_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE123_END 
+0;JMP 
+(COMPARE123_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE123_TRUE 
+D;JLT 
+D=0 
+@COMPARE123_END 
+0;JMP 
+(COMPARE123_TRUE) 
+D=-1 
+(COMPARE123_END) 
+@SP 
+A=M-1 
+M=D 
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE124_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE124_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE124_END 
+0;JMP 
+(COMPARE124_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE124_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE124_END 
+0;JMP 
+(COMPARE124_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE124_TRUE 
+D;JGT 
+D=0 
+@COMPARE124_END 
+0;JMP 
+(COMPARE124_TRUE) 
+D=-1 
+(COMPARE124_END) 
+@SP 
+A=M-1 
+M=D 
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE125 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE125 
+0;JMP 
+(FALSE125) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE125)
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE126_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE126_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE126_END 
+0;JMP 
+(COMPARE126_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE126_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE126_END 
+0;JMP 
+(COMPARE126_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE126_TRUE 
+D;JLT 
+D=0 
+@COMPARE126_END 
+0;JMP 
+(COMPARE126_TRUE) 
+D=-1 
+(COMPARE126_END) 
+@SP 
+A=M-1 
+M=D 
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE127_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE127_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE127_END 
+0;JMP 
+(COMPARE127_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE127_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE127_END 
+0;JMP 
+(COMPARE127_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE127_TRUE 
+D;JGT 
+D=0 
+@COMPARE127_END 
+0;JMP 
+(COMPARE127_TRUE) 
+D=-1 
+(COMPARE127_END) 
+@SP 
+A=M-1 
+M=D 
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE128 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE128 
+0;JMP 
+(FALSE128) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE128)
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE129_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE129_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE129_END 
+0;JMP 
+(COMPARE129_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE129_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE129_END 
+0;JMP 
+(COMPARE129_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE129_TRUE 
+D;JLT 
+D=0 
+@COMPARE129_END 
+0;JMP 
+(COMPARE129_TRUE) 
+D=-1 
+(COMPARE129_END) 
+@SP 
+A=M-1 
+M=D 
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE130_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE130_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE130_END 
+0;JMP 
+(COMPARE130_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE130_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE130_END 
+0;JMP 
+(COMPARE130_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE130_TRUE 
+D;JGT 
+D=0 
+@COMPARE130_END 
+0;JMP 
+(COMPARE130_TRUE) 
+D=-1 
+(COMPARE130_END) 
+@SP 
+A=M-1 
+M=D 
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE131 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE131 
+0;JMP 
+(FALSE131) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE131)
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE132_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE132_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE132_END 
+0;JMP 
+(COMPARE132_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE132_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE132_END 
+0;JMP 
+(COMPARE132_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE132_TRUE 
+D;JLT 
+D=0 
+@COMPARE132_END 
+0;JMP 
+(COMPARE132_TRUE) 
+D=-1 
+(COMPARE132_END) 
+@SP 
+A=M-1 
+M=D 
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE133_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE133_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE133_END 
+0;JMP 
+(COMPARE133_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE133_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE133_END 
+0;JMP 
+(COMPARE133_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE133_TRUE 
+D;JGT 
+D=0 
+@COMPARE133_END 
+0;JMP 
+(COMPARE133_TRUE) 
+D=-1 
+(COMPARE133_END) 
+@SP 
+A=M-1 
+M=D 
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE134 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE134 
+0;JMP 
+(FALSE134) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE134)
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE135_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE135_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE135_END 
+0;JMP 
+(COMPARE135_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE135_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE135_END 
+0;JMP 
+(COMPARE135_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE135_TRUE 
+D;JLT 
+D=0 
+@COMPARE135_END 
+0;JMP 
+(COMPARE135_TRUE) 
+D=-1 
+(COMPARE135_END) 
+@SP 
+A=M-1 
+M=D 
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE136_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE136_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE136_END 
+0;JMP 
+(COMPARE136_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE136_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE136_END 
+0;JMP 
+(COMPARE136_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE136_TRUE 
+D;JGT 
+D=0 
+@COMPARE136_END 
+0;JMP 
+(COMPARE136_TRUE) 
+D=-1 
+(COMPARE136_END) 
+@SP 
+A=M-1 
+M=D 
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE137 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE137 
+0;JMP 
+(FALSE137) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE137)
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE138_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE138_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE138_END 
+0;JMP 
+(COMPARE138_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE138_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE138_END 
+0;JMP 
+(COMPARE138_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE138_TRUE 
+D;JLT 
+D=0 
+@COMPARE138_END 
+0;JMP 
+(COMPARE138_TRUE) 
+D=-1 
+(COMPARE138_END) 
+@SP 
+A=M-1 
+M=D 
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE139_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE139_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE139_END 
+0;JMP 
+(COMPARE139_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE139_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE139_END 
+0;JMP 
+(COMPARE139_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE139_TRUE 
+D;JGT 
+D=0 
+@COMPARE139_END 
+0;JMP 
+(COMPARE139_TRUE) 
+D=-1 
+(COMPARE139_END) 
+@SP 
+A=M-1 
+M=D 
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE140 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE140 
+0;JMP 
+(FALSE140) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE140)
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE141_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE141_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE141_END 
+0;JMP 
+(COMPARE141_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE141_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE141_END 
+0;JMP 
+(COMPARE141_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE141_TRUE 
+D;JLT 
+D=0 
+@COMPARE141_END 
+0;JMP 
+(COMPARE141_TRUE) 
+D=-1 
+(COMPARE141_END) 
+@SP 
+A=M-1 
+M=D 
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE142_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE142_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE142_END 
+0;JMP 
+(COMPARE142_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE142_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE142_END 
+0;JMP 
+(COMPARE142_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE142_TRUE 
+D;JGT 
+D=0 
+@COMPARE142_END 
+0;JMP 
+(COMPARE142_TRUE) 
+D=-1 
+(COMPARE142_END) 
+@SP 
+A=M-1 
+M=D 
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32766
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE143 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE143 
+0;JMP 
+(FALSE143) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE143)
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE144_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE144_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE144_END 
+0;JMP 
+(COMPARE144_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE144_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE144_END 
+0;JMP 
+(COMPARE144_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE144_TRUE 
+D;JLT 
+D=0 
+@COMPARE144_END 
+0;JMP 
+(COMPARE144_TRUE) 
+D=-1 
+(COMPARE144_END) 
+@SP 
+A=M-1 
+M=D 
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE145_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE145_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE145_END 
+0;JMP 
+(COMPARE145_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE145_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE145_END 
+0;JMP 
+(COMPARE145_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE145_TRUE 
+D;JGT 
+D=0 
+@COMPARE145_END 
+0;JMP 
+(COMPARE145_TRUE) 
+D=-1 
+(COMPARE145_END) 
+@SP 
+A=M-1 
+M=D 
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE146 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE146 
+0;JMP 
+(FALSE146) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE146)
+(END) 
+@END 
+0;JMP
@@ -0,0 +1,112 @@
+// Runs CompareTest.asm, translated with or without --compact or
+// --cache-top but with --no-bootstrap, and prints one line per pair:
+// x, y, x < y, x > y and x = y.
+
+load CompareTest.asm,
+output-file CompareTest.out,
+compare-to CompareTest.cmp,
+
+set RAM[0] 256,
+
+repeat 20000 {
+  ticktock;
+}
+
+output-list RAM[256]%D2.6.2 RAM[257]%D2.6.2 RAM[258]%D2.6.2 RAM[259]%D2.6.2 RAM[260]%D2.6.2;
+output;
+output-list RAM[261]%D2.6.2 RAM[262]%D2.6.2 RAM[263]%D2.6.2 RAM[264]%D2.6.2 RAM[265]%D2.6.2;
+output;
+output-list RAM[266]%D2.6.2 RAM[267]%D2.6.2 RAM[268]%D2.6.2 RAM[269]%D2.6.2 RAM[270]%D2.6.2;
+output;
+output-list RAM[271]%D2.6.2 RAM[272]%D2.6.2 RAM[273]%D2.6.2 RAM[274]%D2.6.2 RAM[275]%D2.6.2;
+output;
+output-list RAM[276]%D2.6.2 RAM[277]%D2.6.2 RAM[278]%D2.6.2 RAM[279]%D2.6.2 RAM[280]%D2.6.2;
+output;
+output-list RAM[281]%D2.6.2 RAM[282]%D2.6.2 RAM[283]%D2.6.2 RAM[284]%D2.6.2 RAM[285]%D2.6.2;
+output;
+output-list RAM[286]%D2.6.2 RAM[287]%D2.6.2 RAM[288]%D2.6.2 RAM[289]%D2.6.2 RAM[290]%D2.6.2;
+output;
+output-list RAM[291]%D2.6.2 RAM[292]%D2.6.2 RAM[293]%D2.6.2 RAM[294]%D2.6.2 RAM[295]%D2.6.2;
+output;
+output-list RAM[296]%D2.6.2 RAM[297]%D2.6.2 RAM[298]%D2.6.2 RAM[299]%D2.6.2 RAM[300]%D2.6.2;
+output;
+output-list RAM[301]%D2.6.2 RAM[302]%D2.6.2 RAM[303]%D2.6.2 RAM[304]%D2.6.2 RAM[305]%D2.6.2;
+output;
+output-list RAM[306]%D2.6.2 RAM[307]%D2.6.2 RAM[308]%D2.6.2 RAM[309]%D2.6.2 RAM[310]%D2.6.2;
+output;
+output-list RAM[311]%D2.6.2 RAM[312]%D2.6.2 RAM[313]%D2.6.2 RAM[314]%D2.6.2 RAM[315]%D2.6.2;
+output;
+output-list RAM[316]%D2.6.2 RAM[317]%D2.6.2 RAM[318]%D2.6.2 RAM[319]%D2.6.2 RAM[320]%D2.6.2;
+output;
+output-list RAM[321]%D2.6.2 RAM[322]%D2.6.2 RAM[323]%D2.6.2 RAM[324]%D2.6.2 RAM[325]%D2.6.2;
+output;
+output-list RAM[326]%D2.6.2 RAM[327]%D2.6.2 RAM[328]%D2.6.2 RAM[329]%D2.6.2 RAM[330]%D2.6.2;
+output;
+output-list RAM[331]%D2.6.2 RAM[332]%D2.6.2 RAM[333]%D2.6.2 RAM[334]%D2.6.2 RAM[335]%D2.6.2;
+output;
+output-list RAM[336]%D2.6.2 RAM[337]%D2.6.2 RAM[338]%D2.6.2 RAM[339]%D2.6.2 RAM[340]%D2.6.2;
+output;
+output-list RAM[341]%D2.6.2 RAM[342]%D2.6.2 RAM[343]%D2.6.2 RAM[344]%D2.6.2 RAM[345]%D2.6.2;
+output;
+output-list RAM[346]%D2.6.2 RAM[347]%D2.6.2 RAM[348]%D2.6.2 RAM[349]%D2.6.2 RAM[350]%D2.6.2;
+output;
+output-list RAM[351]%D2.6.2 RAM[352]%D2.6.2 RAM[353]%D2.6.2 RAM[354]%D2.6.2 RAM[355]%D2.6.2;
+output;
+output-list RAM[356]%D2.6.2 RAM[357]%D2.6.2 RAM[358]%D2.6.2 RAM[359]%D2.6.2 RAM[360]%D2.6.2;
+output;
+output-list RAM[361]%D2.6.2 RAM[362]%D2.6.2 RAM[363]%D2.6.2 RAM[364]%D2.6.2 RAM[365]%D2.6.2;
+output;
+output-list RAM[366]%D2.6.2 RAM[367]%D2.6.2 RAM[368]%D2.6.2 RAM[369]%D2.6.2 RAM[370]%D2.6.2;
+output;
+output-list RAM[371]%D2.6.2 RAM[372]%D2.6.2 RAM[373]%D2.6.2 RAM[374]%D2.6.2 RAM[375]%D2.6.2;
+output;
+output-list RAM[376]%D2.6.2 RAM[377]%D2.6.2 RAM[378]%D2.6.2 RAM[379]%D2.6.2 RAM[380]%D2.6.2;
+output;
+output-list RAM[381]%D2.6.2 RAM[382]%D2.6.2 RAM[383]%D2.6.2 RAM[384]%D2.6.2 RAM[385]%D2.6.2;
+output;
+output-list RAM[386]%D2.6.2 RAM[387]%D2.6.2 RAM[388]%D2.6.2 RAM[389]%D2.6.2 RAM[390]%D2.6.2;
+output;
+output-list RAM[391]%D2.6.2 RAM[392]%D2.6.2 RAM[393]%D2.6.2 RAM[394]%D2.6.2 RAM[395]%D2.6.2;
+output;
+output-list RAM[396]%D2.6.2 RAM[397]%D2.6.2 RAM[398]%D2.6.2 RAM[399]%D2.6.2 RAM[400]%D2.6.2;
+output;
+output-list RAM[401]%D2.6.2 RAM[402]%D2.6.2 RAM[403]%D2.6.2 RAM[404]%D2.6.2 RAM[405]%D2.6.2;
+output;
+output-list RAM[406]%D2.6.2 RAM[407]%D2.6.2 RAM[408]%D2.6.2 RAM[409]%D2.6.2 RAM[410]%D2.6.2;
+output;
+output-list RAM[411]%D2.6.2 RAM[412]%D2.6.2 RAM[413]%D2.6.2 RAM[414]%D2.6.2 RAM[415]%D2.6.2;
+output;
+output-list RAM[416]%D2.6.2 RAM[417]%D2.6.2 RAM[418]%D2.6.2 RAM[419]%D2.6.2 RAM[420]%D2.6.2;
+output;
+output-list RAM[421]%D2.6.2 RAM[422]%D2.6.2 RAM[423]%D2.6.2 RAM[424]%D2.6.2 RAM[425]%D2.6.2;
+output;
+output-list RAM[426]%D2.6.2 RAM[427]%D2.6.2 RAM[428]%D2.6.2 RAM[429]%D2.6.2 RAM[430]%D2.6.2;
+output;
+output-list RAM[431]%D2.6.2 RAM[432]%D2.6.2 RAM[433]%D2.6.2 RAM[434]%D2.6.2 RAM[435]%D2.6.2;
+output;
+output-list RAM[436]%D2.6.2 RAM[437]%D2.6.2 RAM[438]%D2.6.2 RAM[439]%D2.6.2 RAM[440]%D2.6.2;
+output;
+output-list RAM[441]%D2.6.2 RAM[442]%D2.6.2 RAM[443]%D2.6.2 RAM[444]%D2.6.2 RAM[445]%D2.6.2;
+output;
+output-list RAM[446]%D2.6.2 RAM[447]%D2.6.2 RAM[448]%D2.6.2 RAM[449]%D2.6.2 RAM[450]%D2.6.2;
+output;
+output-list RAM[451]%D2.6.2 RAM[452]%D2.6.2 RAM[453]%D2.6.2 RAM[454]%D2.6.2 RAM[455]%D2.6.2;
+output;
+output-list RAM[456]%D2.6.2 RAM[457]%D2.6.2 RAM[458]%D2.6.2 RAM[459]%D2.6.2 RAM[460]%D2.6.2;
+output;
+output-list RAM[461]%D2.6.2 RAM[462]%D2.6.2 RAM[463]%D2.6.2 RAM[464]%D2.6.2 RAM[465]%D2.6.2;
+output;
+output-list RAM[466]%D2.6.2 RAM[467]%D2.6.2 RAM[468]%D2.6.2 RAM[469]%D2.6.2 RAM[470]%D2.6.2;
+output;
+output-list RAM[471]%D2.6.2 RAM[472]%D2.6.2 RAM[473]%D2.6.2 RAM[474]%D2.6.2 RAM[475]%D2.6.2;
+output;
+output-list RAM[476]%D2.6.2 RAM[477]%D2.6.2 RAM[478]%D2.6.2 RAM[479]%D2.6.2 RAM[480]%D2.6.2;
+output;
+output-list RAM[481]%D2.6.2 RAM[482]%D2.6.2 RAM[483]%D2.6.2 RAM[484]%D2.6.2 RAM[485]%D2.6.2;
+output;
+output-list RAM[486]%D2.6.2 RAM[487]%D2.6.2 RAM[488]%D2.6.2 RAM[489]%D2.6.2 RAM[490]%D2.6.2;
+output;
+output-list RAM[491]%D2.6.2 RAM[492]%D2.6.2 RAM[493]%D2.6.2 RAM[494]%D2.6.2 RAM[495]%D2.6.2;
+output;
+output-list RAM[496]%D2.6.2 RAM[497]%D2.6.2 RAM[498]%D2.6.2 RAM[499]%D2.6.2 RAM[500]%D2.6.2;
+output;
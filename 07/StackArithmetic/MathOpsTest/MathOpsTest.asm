@@ -0,0 +1,1261 @@
+@7
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@3
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@7
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@3
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@MUL0 
+D=A 
+@__MUL 
+0;JMP 
+(MUL0) 
+@7
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@3
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@7
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@3
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@MUL1 
+D=A 
+@__MUL 
+0;JMP 
+(MUL1) 
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@MUL2 
+D=A 
+@__MUL 
+0;JMP 
+(MUL2) 
+@300
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@300
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@300
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@300
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@MUL3 
+D=A 
+@__MUL 
+0;JMP 
+(MUL3) 
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@MUL4 
+D=A 
+@__MUL 
+0;JMP 
+(MUL4) 
+@181
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@181
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@181
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@181
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@MUL5 
+D=A 
+@__MUL 
+0;JMP 
+(MUL5) 
+@7
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@2
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@7
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@2
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@DIV6 
+D=A 
+@__DIV 
+0;JMP 
+(DIV6) 
+@7
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@2
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@7
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@2
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@DIV7 
+D=A 
+@__DIV 
+0;JMP 
+(DIV7) 
+@7
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@2
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@7
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@2
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@DIV8 
+D=A 
+@__DIV 
+0;JMP 
+(DIV8) 
+@7
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@2
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@7
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@2
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@DIV9 
+D=A 
+@__DIV 
+0;JMP 
+(DIV9) 
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@DIV10 
+D=A 
+@__DIV 
+0;JMP 
+(DIV10) 
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@DIV11 
+D=A 
+@__DIV 
+0;JMP 
+(DIV11) 
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@2
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@2
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@DIV12 
+D=A 
+@__DIV 
+0;JMP 
+(DIV12) 
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@DIV13 
+D=A 
+@__DIV 
+0;JMP 
+(DIV13) 
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@DIV14 
+D=A 
+@__DIV 
+0;JMP 
+(DIV14) 
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@15
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@15
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SHL15 
+D=A 
+@__SHL 
+0;JMP 
+(SHL15) 
+@3
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@4
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@3
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@4
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SHL16 
+D=A 
+@__SHL 
+0;JMP 
+(SHL16) 
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SHL17 
+D=A 
+@__SHL 
+0;JMP 
+(SHL17) 
+@5
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@16
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@5
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@16
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SHL18 
+D=A 
+@__SHL 
+0;JMP 
+(SHL18) 
+@5
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@5
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@SHL19 
+D=A 
+@__SHL 
+0;JMP 
+(SHL19) 
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SHR20 
+D=A 
+@__SHR 
+0;JMP 
+(SHR20) 
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@15
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@15
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SHR21 
+D=A 
+@__SHR 
+0;JMP 
+(SHR21) 
+@100
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@2
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@100
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@2
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SHR22 
+D=A 
+@__SHR 
+0;JMP 
+(SHR22) 
+@7
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@7
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SHR23 
+D=A 
+@__SHR 
+0;JMP 
+(SHR23) 
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@16
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@16
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SHR24 
+D=A 
+@__SHR 
+0;JMP 
+(SHR24) 
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=-M
+@SHR25 
+D=A 
+@__SHR 
+0;JMP 
+(SHR25) 
+(END) 
+@END 
+0;JMP
+(__MUL)
+@R15 
+M=D 
+@SP 
+AM=M-1 
+A=A-1 
+D=M 
+M=0 
+@R14 
+M=D 
+@R13 
+M=1 
+(__MUL_LOOP) 
+@SP 
+A=M 
+D=M 
+@__MUL_END 
+D;JEQ 
+@R13 
+D=D&M 
+@__MUL_NEXT 
+D;JEQ 
+@SP 
+A=M 
+M=M-D 
+@R14 
+D=M 
+@SP 
+A=M-1 
+M=D+M 
+(__MUL_NEXT) 
+@R14 
+D=M 
+M=D+M 
+@R13 
+D=M 
+M=D+M 
+@__MUL_LOOP 
+0;JMP 
+(__MUL_END) 
+@R15 
+A=M 
+0;JMP 
+(__DIV)
+@R15 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@__DIV_Y_POSITIVE 
+D;JGE 
+@R13 
+M=-M 
+@R15 
+M=!M 
+(__DIV_Y_POSITIVE) 
+@SP 
+A=M-1 
+D=M 
+@__DIV_X_POSITIVE 
+D;JGE 
+D=-D 
+@R15 
+M=!M 
+(__DIV_X_POSITIVE) 
+@SP 
+A=M 
+M=D 
+A=A-1 
+M=0 
+@R13 
+D=M 
+@__DIV_MIN 
+D;JLT 
+@__DIV_SIGN 
+D;JEQ 
+D=D-1 
+@__DIV_ONE 
+D;JEQ 
+@SP 
+A=M-1 
+M=1 
+@R14 
+M=0 
+@SP 
+A=M 
+D=M 
+M=D+M 
+@__DIV_LOOP 
+D;JGE 
+@R14 
+M=1 
+(__DIV_LOOP) 
+@R14 
+D=M 
+M=D+M 
+@SP 
+A=M-1 
+D=M 
+M=D+M 
+@SP 
+A=M 
+D=M 
+M=D+M 
+@__DIV_COMPARE 
+D;JGE 
+@R14 
+M=M+1 
+(__DIV_COMPARE) 
+@R14 
+D=M 
+@__DIV_SUBTRACT 
+D;JLT 
+@R13 
+D=D-M 
+@__DIV_NEXT 
+D;JLT 
+(__DIV_SUBTRACT) 
+@R13 
+D=M 
+@R14 
+M=M-D 
+@SP 
+A=M-1 
+M=M+1 
+(__DIV_NEXT) 
+@SP 
+A=M-1 
+D=M 
+@__DIV_LOOP 
+D;JGE 
+@32767 
+D=A 
+@SP 
+A=M-1 
+M=D&M 
+@__DIV_SIGN 
+0;JMP 
+(__DIV_MIN) 
+@SP 
+A=M 
+D=M 
+@__DIV_SIGN 
+D;JGE 
+@SP 
+A=M-1 
+M=1 
+@__DIV_SIGN 
+0;JMP 
+(__DIV_ONE) 
+@SP 
+A=M 
+D=M 
+A=A-1 
+M=D 
+(__DIV_SIGN) 
+@R15 
+D=M 
+@__DIV_END 
+D;JGE 
+@R15 
+M=!M 
+@SP 
+A=M-1 
+M=-M 
+(__DIV_END) 
+@R15 
+A=M 
+0;JMP 
+(__SHL)
+@R15 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+(__SHL_LOOP) 
+@R13 
+D=M 
+@__SHL_END 
+D;JEQ 
+@SP 
+A=M-1 
+D=M 
+@__SHL_END 
+D;JEQ 
+@SP 
+A=M-1 
+M=D+M 
+@R13 
+M=M-1 
+@__SHL_LOOP 
+0;JMP 
+(__SHL_END) 
+@R15 
+A=M 
+0;JMP 
+(__SHR)
+@R15 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@R14 
+M=D 
+@SP 
+A=M-1 
+D=M 
+M=0 
+A=A+1 
+M=D 
+@R13 
+M=1 
+(__SHR_FIND) 
+@R14 
+D=M 
+@__SHR_FOUND 
+D;JEQ 
+@R13 
+D=M 
+@__SHR_FOUND 
+D;JEQ 
+@R13 
+M=D+M 
+@R14 
+M=M-1 
+@__SHR_FIND 
+0;JMP 
+(__SHR_FOUND) 
+@R14 
+M=1 
+(__SHR_LOOP) 
+@R13 
+D=M 
+@__SHR_END 
+D;JEQ 
+@SP 
+A=M 
+D=D&M 
+@__SHR_NEXT 
+D;JEQ 
+@R14 
+D=M 
+@SP 
+A=M-1 
+M=D|M 
+(__SHR_NEXT) 
+@R13 
+D=M 
+M=D+M 
+@R14 
+D=M 
+M=D+M 
+@__SHR_LOOP 
+0;JMP 
+(__SHR_END) 
+@R15 
+A=M 
+0;JMP 
@@ -0,0 +1,66 @@
+// Runs MathOpsTest.asm, translated with or without --compact or
+// --cache-top but with --no-bootstrap, and prints one line per case:
+// x, y and the result.
+
+load MathOpsTest.asm,
+output-file MathOpsTest.out,
+compare-to MathOpsTest.cmp,
+
+set RAM[0] 256,
+
+repeat 50000 {
+  ticktock;
+}
+
+output-list RAM[256]%D2.6.2 RAM[257]%D2.6.2 RAM[258]%D2.6.2;
+output;
+output-list RAM[259]%D2.6.2 RAM[260]%D2.6.2 RAM[261]%D2.6.2;
+output;
+output-list RAM[262]%D2.6.2 RAM[263]%D2.6.2 RAM[264]%D2.6.2;
+output;
+output-list RAM[265]%D2.6.2 RAM[266]%D2.6.2 RAM[267]%D2.6.2;
+output;
+output-list RAM[268]%D2.6.2 RAM[269]%D2.6.2 RAM[270]%D2.6.2;
+output;
+output-list RAM[271]%D2.6.2 RAM[272]%D2.6.2 RAM[273]%D2.6.2;
+output;
+output-list RAM[274]%D2.6.2 RAM[275]%D2.6.2 RAM[276]%D2.6.2;
+output;
+output-list RAM[277]%D2.6.2 RAM[278]%D2.6.2 RAM[279]%D2.6.2;
+output;
+output-list RAM[280]%D2.6.2 RAM[281]%D2.6.2 RAM[282]%D2.6.2;
+output;
+output-list RAM[283]%D2.6.2 RAM[284]%D2.6.2 RAM[285]%D2.6.2;
+output;
+output-list RAM[286]%D2.6.2 RAM[287]%D2.6.2 RAM[288]%D2.6.2;
+output;
+output-list RAM[289]%D2.6.2 RAM[290]%D2.6.2 RAM[291]%D2.6.2;
+output;
+output-list RAM[292]%D2.6.2 RAM[293]%D2.6.2 RAM[294]%D2.6.2;
+output;
+output-list RAM[295]%D2.6.2 RAM[296]%D2.6.2 RAM[297]%D2.6.2;
+output;
+output-list RAM[298]%D2.6.2 RAM[299]%D2.6.2 RAM[300]%D2.6.2;
+output;
+output-list RAM[301]%D2.6.2 RAM[302]%D2.6.2 RAM[303]%D2.6.2;
+output;
+output-list RAM[304]%D2.6.2 RAM[305]%D2.6.2 RAM[306]%D2.6.2;
+output;
+output-list RAM[307]%D2.6.2 RAM[308]%D2.6.2 RAM[309]%D2.6.2;
+output;
+output-list RAM[310]%D2.6.2 RAM[311]%D2.6.2 RAM[312]%D2.6.2;
+output;
+output-list RAM[313]%D2.6.2 RAM[314]%D2.6.2 RAM[315]%D2.6.2;
+output;
+output-list RAM[316]%D2.6.2 RAM[317]%D2.6.2 RAM[318]%D2.6.2;
+output;
+output-list RAM[319]%D2.6.2 RAM[320]%D2.6.2 RAM[321]%D2.6.2;
+output;
+output-list RAM[322]%D2.6.2 RAM[323]%D2.6.2 RAM[324]%D2.6.2;
+output;
+output-list RAM[325]%D2.6.2 RAM[326]%D2.6.2 RAM[327]%D2.6.2;
+output;
+output-list RAM[328]%D2.6.2 RAM[329]%D2.6.2 RAM[330]%D2.6.2;
+output;
+output-list RAM[331]%D2.6.2 RAM[332]%D2.6.2 RAM[333]%D2.6.2;
+output;
@@ -0,0 +1,297 @@
+@256 
+D=A 
+@SP 
+M=D 
+@CALL0 
+D=A 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@ARG 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@THIS 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@THAT 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@5 
+D=A 
+@SP 
+D=M-D 
+@ARG 
+M=D 
+@SP 
+D=M 
+@LCL 
+M=D 
+@Sys.init 
+0;JMP 
+(CALL0) 
+(Sys.init)
+@SP 
+D=M 
+@2042 
+D=D-A 
+@__FAIL1_1 
+D;JGT 
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@CALL1 
+D=A 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@ARG 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@THIS 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@THAT 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@6 
+D=A 
+@SP 
+D=M-D 
+@ARG 
+M=D 
+@SP 
+D=M 
+@LCL 
+M=D 
+@Sys.recurse 
+0;JMP 
+(CALL1) 
+@SP 
+AM=M-1 
+D=M 
+@5 
+M=D 
+(Sys.init$HALT)
+@Sys.init$HALT 
+0;JMP 
+(Sys.recurse)
+@SP 
+D=M 
+@2041 
+D=D-A 
+@__FAIL1_2 
+D;JGT 
+@SP 
+A=M 
+M=0
+@1 
+D=A 
+@SP 
+M=D+M 
+@ARG 
+D=M 
+@0 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+M=D+M
+@LCL 
+D=M 
+@0 
+D=D+A 
+@R13 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@R13 
+A=M 
+M=D 
+@LCL 
+D=M 
+@0 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@CALL2 
+D=A 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@ARG 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@THIS 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@THAT 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@6 
+D=A 
+@SP 
+D=M-D 
+@ARG 
+M=D 
+@SP 
+D=M 
+@LCL 
+M=D 
+@Sys.recurse 
+0;JMP 
+(CALL2) 
+@LCL 
+D=M 
+@R14 
+M=D 
+@5 
+A=D-A 
+D=M 
+@R15 
+M=D 
+@SP 
+A=M-1 
+D=M 
+@ARG 
+A=M 
+M=D 
+@ARG 
+D=M+1 
+@SP 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@THAT 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@THIS 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@ARG 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@LCL 
+M=D 
+@R15 
+A=M 
+0;JMP 
+(END) 
+@END 
+0;JMP
+(__FAIL1_1)
+@1 
+D=A 
+@R14 
+M=D 
+@1 
+D=A 
+@__FAILED 
+0;JMP 
+(__FAILED)
+@R13 
+M=D 
+(__FAILED_HALT) 
+@__FAILED_HALT 
+0;JMP 
+(__FAIL1_2)
+@2 
+D=A 
+@R14 
+M=D 
+@1 
+D=A 
+@__FAILED 
+0;JMP 
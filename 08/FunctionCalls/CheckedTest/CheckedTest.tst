@@ -0,0 +1,16 @@
+// Runs CheckedTest.asm, translated with --checked, and checks that it
+// stopped with a stack overflow in Sys.recurse: the error code in RAM[13],
+// the function's ID in RAM[14], and the heap at RAM[2048] untouched.
+
+load CheckedTest.asm,
+output-file CheckedTest.out,
+compare-to CheckedTest.cmp,
+output-list RAM[0]%D2.6.2 RAM[13]%D2.6.2 RAM[14]%D2.6.2 RAM[2048]%D2.6.2;
+
+set RAM[2048] 12345,
+
+repeat 100000 {
+  ticktock;
+}
+
+output;
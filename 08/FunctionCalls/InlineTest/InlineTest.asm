@@ -0,0 +1,1185 @@
+@256 
+D=A 
+@SP 
+M=D 
+@CALL0 
+D=A 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@ARG 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@THIS 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@THAT 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@5 
+D=A 
+@SP 
+D=M-D 
+@ARG 
+M=D 
+@SP 
+D=M 
+@LCL 
+M=D 
+@Sys.init 
+0;JMP 
+(CALL0) 
+(Counter.next)
+@Counter.0
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+M=D+M
+@SP 
+AM=M-1 
+D=M 
+@Counter.0 
+M=D 
+@Counter.0
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@R14 
+M=D 
+@5 
+A=D-A 
+D=M 
+@R15 
+M=D 
+@SP 
+A=M-1 
+D=M 
+@ARG 
+A=M 
+M=D 
+@ARG 
+D=M+1 
+@SP 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@THAT 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@THIS 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@ARG 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@LCL 
+M=D 
+@R15 
+A=M 
+0;JMP 
+(Point.getX)
+@ARG 
+D=M 
+@0 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@3 
+M=D 
+@THIS 
+D=M 
+@0 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@R14 
+M=D 
+@5 
+A=D-A 
+D=M 
+@R15 
+M=D 
+@SP 
+A=M-1 
+D=M 
+@ARG 
+A=M 
+M=D 
+@ARG 
+D=M+1 
+@SP 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@THAT 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@THIS 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@ARG 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@LCL 
+M=D 
+@R15 
+A=M 
+0;JMP 
+(Point.setX)
+@ARG 
+D=M 
+@0 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@3 
+M=D 
+@ARG 
+D=M 
+@1 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@THIS 
+D=M 
+@0 
+D=D+A 
+@R13 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@R13 
+A=M 
+M=D 
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@R14 
+M=D 
+@5 
+A=D-A 
+D=M 
+@R15 
+M=D 
+@SP 
+A=M-1 
+D=M 
+@ARG 
+A=M 
+M=D 
+@ARG 
+D=M+1 
+@SP 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@THAT 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@THIS 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@ARG 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@LCL 
+M=D 
+@R15 
+A=M 
+0;JMP 
+(Point.sum3)
+@SP 
+A=M 
+M=0
+@1 
+D=A 
+@SP 
+M=D+M 
+@ARG 
+D=M 
+@1 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@ARG 
+D=M 
+@0 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+M=M-D
+@LCL 
+D=M 
+@0 
+D=D+A 
+@R13 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@R13 
+A=M 
+M=D 
+@LCL 
+D=M 
+@0 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@ARG 
+D=M 
+@2 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+M=D+M
+@LCL 
+D=M 
+@R14 
+M=D 
+@5 
+A=D-A 
+D=M 
+@R15 
+M=D 
+@SP 
+A=M-1 
+D=M 
+@ARG 
+A=M 
+M=D 
+@ARG 
+D=M+1 
+@SP 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@THAT 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@THIS 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@ARG 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@LCL 
+M=D 
+@R15 
+A=M 
+0;JMP 
+(Point.fresh)
+@SP 
+A=M 
+M=0
+@1 
+D=A 
+@SP 
+M=D+M 
+@LCL 
+D=M 
+@0 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+M=D+M
+@LCL 
+D=M 
+@0 
+D=D+A 
+@R13 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@R13 
+A=M 
+M=D 
+@LCL 
+D=M 
+@0 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@R14 
+M=D 
+@5 
+A=D-A 
+D=M 
+@R15 
+M=D 
+@SP 
+A=M-1 
+D=M 
+@ARG 
+A=M 
+M=D 
+@ARG 
+D=M+1 
+@SP 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@THAT 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@THIS 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@ARG 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@LCL 
+M=D 
+@R15 
+A=M 
+0;JMP 
+(Sys.init)
+@SP 
+A=M 
+M=0
+A=A+1 
+M=0
+A=A+1 
+M=0
+A=A+1 
+M=0
+A=A+1 
+M=0
+@5 
+D=A 
+@SP 
+M=D+M 
+@3000
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@3 
+M=D 
+@5000
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@4 
+M=D 
+@4000
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@7
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@2 
+D=D+A 
+@R13 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@R13 
+A=M 
+M=D 
+@LCL 
+D=M 
+@1 
+D=D+A 
+@R13 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@R13 
+A=M 
+M=D 
+@3
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@3 
+D=D+A 
+@R13 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@R13 
+A=M 
+M=D 
+@LCL 
+D=M 
+@1 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@3 
+M=D 
+@LCL 
+D=M 
+@2 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@THIS 
+D=M 
+@0 
+D=D+A 
+@R13 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@R13 
+A=M 
+M=D 
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@3 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@3 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@5 
+M=D 
+@4000
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@1 
+D=D+A 
+@R13 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@R13 
+A=M 
+M=D 
+@3
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@2 
+D=D+A 
+@R13 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@R13 
+A=M 
+M=D 
+@LCL 
+D=M 
+@1 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@3 
+M=D 
+@THIS 
+D=M 
+@0 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@2 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@3 
+M=D 
+@THAT 
+D=M 
+@0 
+D=D+A 
+@R13 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@R13 
+A=M 
+M=D 
+@3
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@THAT 
+D=M 
+@1 
+D=D+A 
+@R13 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@R13 
+A=M 
+M=D 
+@2
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@3
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@10
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@3 
+D=D+A 
+@R13 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@R13 
+A=M 
+M=D 
+@LCL 
+D=M 
+@2 
+D=D+A 
+@R13 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@R13 
+A=M 
+M=D 
+@LCL 
+D=M 
+@1 
+D=D+A 
+@R13 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@R13 
+A=M 
+M=D 
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@4 
+D=D+A 
+@R13 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@R13 
+A=M 
+M=D 
+@LCL 
+D=M 
+@2 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@1 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+M=M-D
+@LCL 
+D=M 
+@4 
+D=D+A 
+@R13 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@R13 
+A=M 
+M=D 
+@LCL 
+D=M 
+@4 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@3 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+M=D+M
+@THAT 
+D=M 
+@2 
+D=D+A 
+@R13 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@R13 
+A=M 
+M=D 
+@3
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@0 
+D=D+A 
+@R13 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@R13 
+A=M 
+M=D 
+(Sys.init$LOOP)
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@1 
+D=D+A 
+@R13 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@R13 
+A=M 
+M=D 
+@LCL 
+D=M 
+@1 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+M=D+M
+@LCL 
+D=M 
+@1 
+D=D+A 
+@R13 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@R13 
+A=M 
+M=D 
+@LCL 
+D=M 
+@1 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@THAT 
+D=M 
+@3 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+M=D+M
+@THAT 
+D=M 
+@3 
+D=D+A 
+@R13 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@R13 
+A=M 
+M=D 
+@LCL 
+D=M 
+@0 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+M=M-D
+@LCL 
+D=M 
+@0 
+D=D+A 
+@R13 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@R13 
+A=M 
+M=D 
+@LCL 
+D=M 
+@0 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@Sys.init$LOOP 
+D;JNE 
+@CALL1 
+D=A 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@ARG 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@THIS 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@THAT 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@5 
+D=A 
+@SP 
+D=M-D 
+@ARG 
+M=D 
+@SP 
+D=M 
+@LCL 
+M=D 
+@Counter.next 
+0;JMP 
+(CALL1) 
+@CALL2 
+D=A 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@ARG 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@THIS 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@THAT 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@5 
+D=A 
+@SP 
+D=M-D 
+@ARG 
+M=D 
+@SP 
+D=M 
+@LCL 
+M=D 
+@Counter.next 
+0;JMP 
+(CALL2) 
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+M=D+M
+@THAT 
+D=M 
+@4 
+D=D+A 
+@R13 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@R13 
+A=M 
+M=D 
+(Sys.init$HALT)
+@Sys.init$HALT 
+0;JMP 
+(END) 
+@END 
+0;JMP
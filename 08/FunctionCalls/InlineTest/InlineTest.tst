@@ -0,0 +1,15 @@
+// Runs InlineTest.asm, translated with --inline, and checks the results
+// of inlined getters, setters and functions with locals. SP ends just
+// above Sys.init's frame, whose 1 local is followed by the 4 the inlined
+// calls need at most.
+
+load InlineTest.asm,
+output-file InlineTest.out,
+compare-to InlineTest.cmp,
+output-list RAM[0]%D2.6.2 RAM[5000]%D2.6.2 RAM[5001]%D2.6.2 RAM[5002]%D2.6.2 RAM[5003]%D2.6.2 RAM[5004]%D2.6.2;
+
+repeat 5000 {
+  ticktock;
+}
+
+output;
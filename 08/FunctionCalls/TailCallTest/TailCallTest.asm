@@ -0,0 +1,1047 @@
+@256 
+D=A 
+@SP 
+M=D 
+@CALL0 
+D=A 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@ARG 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@THIS 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@THAT 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@5 
+D=A 
+@SP 
+D=M-D 
+@ARG 
+M=D 
+@SP 
+D=M 
+@LCL 
+M=D 
+@Sys.init 
+0;JMP 
+(CALL0) 
+(Main.count)
+@SP 
+A=M 
+M=0
+@1 
+D=A 
+@SP 
+M=D+M 
+@LCL 
+D=M 
+@0 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@Main.count$BAD 
+D;JNE 
+@ARG 
+D=M 
+@0 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@0 
+D=D+A 
+@R13 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@R13 
+A=M 
+M=D 
+@ARG 
+D=M 
+@0 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE1 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE1 
+0;JMP 
+(FALSE1) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE1)
+@SP 
+AM=M-1 
+D=M 
+@Main.count$DONE 
+D;JNE 
+@ARG 
+D=M 
+@0 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+M=M-D
+@ARG 
+D=M 
+@1 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+M=D+M
+@LCL 
+D=M 
+@ARG 
+D=D-M 
+@7 
+D=D-A 
+@TAIL2 
+D;JNE 
+@ARG 
+D=M 
+@0 
+D=D+A 
+@R14 
+M=D 
+@SP 
+D=M 
+@2 
+A=D-A 
+D=M 
+@R14 
+A=M 
+M=D 
+@ARG 
+D=M 
+@1 
+D=D+A 
+@R14 
+M=D 
+@SP 
+D=M 
+@1 
+A=D-A 
+D=M 
+@R14 
+A=M 
+M=D 
+@LCL 
+D=M 
+@SP 
+M=D 
+@Main.count 
+0;JMP 
+(TAIL2) 
+@2 
+D=A 
+@R13 
+M=D 
+@Main.count 
+D=A 
+@R14 
+M=D 
+@__TAIL_CALL 
+0;JMP 
+(Main.count$DONE)
+@ARG 
+D=M 
+@1 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@R14 
+M=D 
+@5 
+A=D-A 
+D=M 
+@R15 
+M=D 
+@SP 
+A=M-1 
+D=M 
+@ARG 
+A=M 
+M=D 
+@ARG 
+D=M+1 
+@SP 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@THAT 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@THIS 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@ARG 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@LCL 
+M=D 
+@R15 
+A=M 
+0;JMP 
+(Main.count$BAD)
+@12345
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@R14 
+M=D 
+@5 
+A=D-A 
+D=M 
+@R15 
+M=D 
+@SP 
+A=M-1 
+D=M 
+@ARG 
+A=M 
+M=D 
+@ARG 
+D=M+1 
+@SP 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@THAT 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@THIS 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@ARG 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@LCL 
+M=D 
+@R15 
+A=M 
+0;JMP 
+(Main.even)
+@ARG 
+D=M 
+@0 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE3 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE3 
+0;JMP 
+(FALSE3) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE3)
+@SP 
+AM=M-1 
+D=M 
+@Main.even$ZERO 
+D;JNE 
+@ARG 
+D=M 
+@0 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+M=M-D
+@1 
+D=A 
+@R13 
+M=D 
+@Main.odd 
+D=A 
+@R14 
+M=D 
+@__TAIL_CALL 
+0;JMP 
+(Main.even$ZERO)
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+A=M-1 
+M=!M
+@LCL 
+D=M 
+@R14 
+M=D 
+@5 
+A=D-A 
+D=M 
+@R15 
+M=D 
+@SP 
+A=M-1 
+D=M 
+@ARG 
+A=M 
+M=D 
+@ARG 
+D=M+1 
+@SP 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@THAT 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@THIS 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@ARG 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@LCL 
+M=D 
+@R15 
+A=M 
+0;JMP 
+(Main.odd)
+@ARG 
+D=M 
+@0 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+D=M-D 
+@FALSE4 
+D;JEQ 
+@SP 
+A=M-1 
+M=0 
+@CONTINUE4 
+0;JMP 
+(FALSE4) 
+@SP 
+A=M-1 
+M=-1 
+(CONTINUE4)
+@SP 
+AM=M-1 
+D=M 
+@Main.odd$ZERO 
+D;JNE 
+@ARG 
+D=M 
+@0 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+M=M-D
+@1 
+D=A 
+@R13 
+M=D 
+@Main.even 
+D=A 
+@R14 
+M=D 
+@__TAIL_CALL 
+0;JMP 
+(Main.odd$ZERO)
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@R14 
+M=D 
+@5 
+A=D-A 
+D=M 
+@R15 
+M=D 
+@SP 
+A=M-1 
+D=M 
+@ARG 
+A=M 
+M=D 
+@ARG 
+D=M+1 
+@SP 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@THAT 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@THIS 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@ARG 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@LCL 
+M=D 
+@R15 
+A=M 
+0;JMP 
+(Main.widen)
+@ARG 
+D=M 
+@0 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@ARG 
+D=M 
+@0 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+M=D+M
+@ARG 
+D=M 
+@0 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@2
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+M=D+M
+@3 
+D=A 
+@R13 
+M=D 
+@Main.spread 
+D=A 
+@R14 
+M=D 
+@__TAIL_CALL 
+0;JMP 
+(Main.spread)
+@ARG 
+D=M 
+@0 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@ARG 
+D=M 
+@1 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+M=D+M
+@ARG 
+D=M 
+@2 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+M=D+M
+@SP 
+AM=M-1 
+D=M 
+@Main.0 
+M=D 
+@0 
+D=A 
+@R13 
+M=D 
+@Main.finish 
+D=A 
+@R14 
+M=D 
+@__TAIL_CALL 
+0;JMP 
+(Main.finish)
+@Main.0
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1000
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+M=D+M
+@LCL 
+D=M 
+@R14 
+M=D 
+@5 
+A=D-A 
+D=M 
+@R15 
+M=D 
+@SP 
+A=M-1 
+D=M 
+@ARG 
+A=M 
+M=D 
+@ARG 
+D=M+1 
+@SP 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@THAT 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@THIS 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@ARG 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@LCL 
+M=D 
+@R15 
+A=M 
+0;JMP 
+(Sys.init)
+@150
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@CALL5 
+D=A 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@ARG 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@THIS 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@THAT 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@7 
+D=A 
+@SP 
+D=M-D 
+@ARG 
+M=D 
+@SP 
+D=M 
+@LCL 
+M=D 
+@Main.count 
+0;JMP 
+(CALL5) 
+@SP 
+AM=M-1 
+D=M 
+@Sys.0 
+M=D 
+@201
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@CALL6 
+D=A 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@ARG 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@THIS 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@THAT 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@6 
+D=A 
+@SP 
+D=M-D 
+@ARG 
+M=D 
+@SP 
+D=M 
+@LCL 
+M=D 
+@Main.even 
+0;JMP 
+(CALL6) 
+@SP 
+AM=M-1 
+D=M 
+@Sys.1 
+M=D 
+@7
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@CALL7 
+D=A 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@ARG 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@THIS 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@THAT 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@6 
+D=A 
+@SP 
+D=M-D 
+@ARG 
+M=D 
+@SP 
+D=M 
+@LCL 
+M=D 
+@Main.widen 
+0;JMP 
+(CALL7) 
+@SP 
+AM=M-1 
+D=M 
+@Sys.2 
+M=D 
+(Sys.init$HALT)
+@Sys.init$HALT 
+0;JMP 
+(END) 
+@END 
+0;JMP
+(__TAIL_CALL)
+@LCL 
+D=M 
+@5 
+A=D-A 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@4 
+A=D-A 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@3 
+A=D-A 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@2 
+A=D-A 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@1 
+A=D-A 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@R13 
+D=M 
+@5 
+D=D+A 
+@SP 
+D=M-D 
+@R13 
+M=D 
+@ARG 
+D=M 
+@R15 
+M=D 
+(__TAIL_CALL_LOOP) 
+@R13 
+A=M 
+D=M 
+@R15 
+A=M 
+M=D 
+@R15 
+M=M+1 
+@R13 
+MD=M+1 
+@SP 
+D=D-M 
+@__TAIL_CALL_LOOP 
+D;JNE 
+@R15 
+D=M 
+@SP 
+M=D 
+@LCL 
+M=D 
+@R14 
+A=M 
+0;JMP 
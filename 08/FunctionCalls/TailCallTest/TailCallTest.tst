@@ -0,0 +1,19 @@
+// Runs TailCallTest.asm, translated with --tail-calls, and checks the
+// results of recursing 150 and 201 deep, and of tail calls with more and
+// fewer arguments than the caller was given. SP ends just above
+// Sys.init's frame, and the stack never grows as far as RAM[1400]. The
+// VM emulator has room for the frames without tail calls, so
+// `vm_difftest TailCallTest --tail-calls` can compare the two.
+
+load TailCallTest.asm,
+output-file TailCallTest.out,
+compare-to TailCallTest.cmp,
+output-list RAM[0]%D2.6.2 RAM[17]%D2.6.2 RAM[18]%D2.6.2 RAM[19]%D2.6.2 RAM[1400]%D2.6.2;
+
+set RAM[1400] 12345,
+
+repeat 200000 {
+  ticktock;
+}
+
+output;
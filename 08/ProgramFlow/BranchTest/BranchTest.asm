@@ -0,0 +1,1185 @@
+@256 
+D=A 
+@SP 
+M=D 
+@CALL0 
+D=A 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@ARG 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@THIS 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@THAT 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@5 
+D=A 
+@SP 
+D=M-D 
+@ARG 
+M=D 
+@SP 
+D=M 
+@LCL 
+M=D 
+@Sys.init 
+0;JMP 
+(CALL0) 
+(Main.main)
+@SP 
+A=M 
+M=0
+A=A+1 
+M=0
+A=A+1 
+M=0
+@3 
+D=A 
+@SP 
+M=D+M 
+@5000
+D=A
+@LCL 
+A=M 
+M=D 
+@0
+D=A
+@LCL 
+A=M 
+A=A+1 
+M=D 
+@0
+D=A
+@LCL 
+A=M 
+A=A+1 
+A=A+1 
+M=D 
+(Main.main$WHILE_LOOP0)
+@LCL 
+D=M 
+@1 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@9
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE1_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE1_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE1_END 
+0;JMP 
+(COMPARE1_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE1_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE1_END 
+0;JMP 
+(COMPARE1_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE1_TRUE 
+D;JGT 
+D=0 
+@COMPARE1_END 
+0;JMP 
+(COMPARE1_TRUE) 
+D=-1 
+(COMPARE1_END) 
+@SP 
+A=M-1 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@Main.main$WHILE_CONTINUE0 
+D;JNE 
+@LCL 
+D=M 
+@2 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@1 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+M=D+M
+@LCL 
+D=M 
+@2 
+D=D+A 
+@R13 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@R13 
+A=M 
+M=D 
+@LCL 
+D=M 
+@1 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+M=D+M
+@LCL 
+D=M 
+@1 
+D=D+A 
+@R13 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@R13 
+A=M 
+M=D 
+@Main.main$WHILE_LOOP0 
+0;JMP 
+(Main.main$WHILE_CONTINUE0)
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@0 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+M=D+M
+@LCL 
+D=M 
+@2 
+A=D+A 
+D=M
+@5 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@4 
+M=D 
+@5
+D=M
+@THAT 
+A=M 
+M=D 
+(Main.main$WHILE_LOOP1)
+@LCL 
+D=M 
+@1 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE2_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE2_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE2_END 
+0;JMP 
+(COMPARE2_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE2_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE2_END 
+0;JMP 
+(COMPARE2_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE2_TRUE 
+D;JLT 
+D=0 
+@COMPARE2_END 
+0;JMP 
+(COMPARE2_TRUE) 
+D=-1 
+(COMPARE2_END) 
+@SP 
+A=M-1 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@Main.main$WHILE_CONTINUE1 
+D;JNE 
+@LCL 
+D=M 
+@1 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+M=M-D
+@LCL 
+D=M 
+@1 
+D=D+A 
+@R13 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@R13 
+A=M 
+M=D 
+@Main.main$WHILE_LOOP1 
+0;JMP 
+(Main.main$WHILE_CONTINUE1)
+@1
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@0 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+M=D+M
+@LCL 
+D=M 
+@1 
+A=D+A 
+D=M
+@5 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@4 
+M=D 
+@5
+D=M
+@THAT 
+A=M 
+M=D 
+@LCL 
+D=M 
+@2 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@4
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE3_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE3_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE3_END 
+0;JMP 
+(COMPARE3_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE3_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE3_END 
+0;JMP 
+(COMPARE3_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE3_TRUE 
+D;JLT 
+D=0 
+@COMPARE3_END 
+0;JMP 
+(COMPARE3_TRUE) 
+D=-1 
+(COMPARE3_END) 
+@SP 
+A=M-1 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@Main.main$IF_ELSE2 
+D;JNE 
+@2
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@0 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+M=D+M
+@1
+D=A
+@5 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@4 
+M=D 
+@5
+D=M
+@THAT 
+A=M 
+M=D 
+@Main.main$IF_CONTINUE2 
+0;JMP 
+(Main.main$IF_ELSE2)
+@2
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@0 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+M=D+M
+@2
+D=A
+@5 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@4 
+M=D 
+@5
+D=M
+@THAT 
+A=M 
+M=D 
+(Main.main$IF_CONTINUE2)
+@LCL 
+D=M 
+@2 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@32767
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE4_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE4_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE4_END 
+0;JMP 
+(COMPARE4_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE4_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE4_END 
+0;JMP 
+(COMPARE4_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE4_TRUE 
+D;JGT 
+D=0 
+@COMPARE4_END 
+0;JMP 
+(COMPARE4_TRUE) 
+D=-1 
+(COMPARE4_END) 
+@SP 
+A=M-1 
+M=D 
+@SP 
+A=M-1 
+M=!M
+@SP 
+AM=M-1 
+D=M 
+@Main.main$IF_ELSE3 
+D;JNE 
+@3
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@0 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+M=D+M
+@1
+D=A
+@5 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@4 
+M=D 
+@5
+D=M
+@THAT 
+A=M 
+M=D 
+@Main.main$IF_CONTINUE3 
+0;JMP 
+(Main.main$IF_ELSE3)
+@3
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@0 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+M=D+M
+@2
+D=A
+@5 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@4 
+M=D 
+@5
+D=M
+@THAT 
+A=M 
+M=D 
+(Main.main$IF_CONTINUE3)
+@LCL 
+D=M 
+@2 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@44
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE5_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE5_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE5_END 
+0;JMP 
+(COMPARE5_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE5_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE5_END 
+0;JMP 
+(COMPARE5_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE5_TRUE 
+D;JGT 
+D=0 
+@COMPARE5_END 
+0;JMP 
+(COMPARE5_TRUE) 
+D=-1 
+(COMPARE5_END) 
+@SP 
+A=M-1 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@Main.main$IF_ELSE4 
+D;JNE 
+@4
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@0 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+M=D+M
+@1
+D=A
+@5 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@4 
+M=D 
+@5
+D=M
+@THAT 
+A=M 
+M=D 
+@Main.main$IF_CONTINUE4 
+0;JMP 
+(Main.main$IF_ELSE4)
+@4
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@0 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+M=D+M
+@2
+D=A
+@5 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@4 
+M=D 
+@5
+D=M
+@THAT 
+A=M 
+M=D 
+(Main.main$IF_CONTINUE4)
+@45
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@2 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE6_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE6_SAME_SIGN 
+D;JGE 
+D=-1 
+@COMPARE6_END 
+0;JMP 
+(COMPARE6_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE6_SAME_SIGN 
+D;JLT 
+D=0 
+@COMPARE6_END 
+0;JMP 
+(COMPARE6_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE6_TRUE 
+D;JLT 
+D=0 
+@COMPARE6_END 
+0;JMP 
+(COMPARE6_TRUE) 
+D=-1 
+(COMPARE6_END) 
+@SP 
+A=M-1 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@Main.main$IF_ELSE5 
+D;JNE 
+@5
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@0 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+M=D+M
+@1
+D=A
+@5 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@4 
+M=D 
+@5
+D=M
+@THAT 
+A=M 
+M=D 
+@Main.main$IF_CONTINUE5 
+0;JMP 
+(Main.main$IF_ELSE5)
+@5
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@0 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+M=D+M
+@2
+D=A
+@5 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@4 
+M=D 
+@5
+D=M
+@THAT 
+A=M 
+M=D 
+(Main.main$IF_CONTINUE5)
+@LCL 
+D=M 
+@2 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@44
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+@R13 
+M=D 
+@COMPARE7_Y_NEGATIVE 
+D;JLT 
+@SP 
+A=M-1 
+D=M 
+@COMPARE7_SAME_SIGN 
+D;JGE 
+D=0 
+@COMPARE7_END 
+0;JMP 
+(COMPARE7_Y_NEGATIVE) 
+@SP 
+A=M-1 
+D=M 
+@COMPARE7_SAME_SIGN 
+D;JLT 
+D=-1 
+@COMPARE7_END 
+0;JMP 
+(COMPARE7_SAME_SIGN) 
+@R13 
+D=D-M 
+@COMPARE7_TRUE 
+D;JGT 
+D=0 
+@COMPARE7_END 
+0;JMP 
+(COMPARE7_TRUE) 
+D=-1 
+(COMPARE7_END) 
+@SP 
+A=M-1 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@Main.main$IF_ELSE6 
+D;JNE 
+@6
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@0 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+M=D+M
+@1
+D=A
+@5 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@4 
+M=D 
+@5
+D=M
+@THAT 
+A=M 
+M=D 
+@Main.main$IF_CONTINUE6 
+0;JMP 
+(Main.main$IF_ELSE6)
+@6
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@0 
+A=D+A 
+D=M
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@SP 
+AM=M-1 
+D=M 
+A=A-1 
+M=D+M
+@2
+D=A
+@5 
+M=D 
+@SP 
+AM=M-1 
+D=M 
+@4 
+M=D 
+@5
+D=M
+@THAT 
+A=M 
+M=D 
+(Main.main$IF_CONTINUE6)
+@0
+D=A
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@R14 
+M=D 
+@5 
+A=D-A 
+D=M 
+@R15 
+M=D 
+@SP 
+A=M-1 
+D=M 
+@ARG 
+A=M 
+M=D 
+@ARG 
+D=M+1 
+@SP 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@THAT 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@THIS 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@ARG 
+M=D 
+@R14 
+AM=M-1 
+D=M 
+@LCL 
+M=D 
+@R15 
+A=M 
+0;JMP 
+(Sys.init)
+@CALL8 
+D=A 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@LCL 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@ARG 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@THIS 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@THAT 
+D=M 
+@SP 
+A=M 
+M=D 
+@SP 
+M=M+1
+@5 
+D=A 
+@SP 
+D=M-D 
+@ARG 
+M=D 
+@SP 
+D=M 
+@LCL 
+M=D 
+@Main.main 
+0;JMP 
+(CALL8) 
+@SP 
+AM=M-1 
+D=M 
+@5 
+M=D 
+(Sys.init$HALT)
+@Sys.init$HALT 
+0;JMP 
+(END) 
+@END 
+0;JMP
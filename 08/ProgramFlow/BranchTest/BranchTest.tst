@@ -0,0 +1,15 @@
+// Runs BranchTest.asm, translated from Main.vm and Sys.vm with -O, which
+// turns most of the `not; if-goto` pairs Main.vm has into the opposite
+// comparison, and checks the branches still go the same way. Optimized
+// holds what vm_optimizer makes of the .vm files.
+
+load BranchTest.asm,
+output-file BranchTest.out,
+compare-to BranchTest.cmp,
+output-list RAM[5000]%D2.6.2 RAM[5001]%D2.6.2 RAM[5002]%D2.6.2 RAM[5003]%D2.6.2 RAM[5004]%D2.6.2 RAM[5005]%D2.6.2 RAM[5006]%D2.6.2;
+
+repeat 20000 {
+  ticktock;
+}
+
+output;
@@ -0,0 +1,126 @@
+use std::env;
+use std::fs;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use std::process;
+
+extern crate getopts;
+use getopts::Options;
+use getopts::ParsingStyle;
+
+extern crate vm_translator;
+use vm_translator::{read_sources, Source, TranslateOptions};
+
+extern crate vm_emulator;
+use vm_emulator::difftest::compare;
+use vm_emulator::random_program::generate;
+
+// Differences printed for each program
+const MAX_SHOWN: usize = 20;
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let program = args[0].clone();
+
+    let mut opts = Options::new();
+    opts.optopt("f", "fuzz", "compare this many random programs instead of FILE", "COUNT");
+    opts.optopt("", "seed", "seed of the first random program, 1 by default", "SEED");
+    opts.optopt("", "keep", "save random programs that differ to this directory", "DIR");
+    opts.optflag("", "no-bootstrap", "translate without bootstrap code and start with SP = 256");
+    opts.optflag("c", "compact", "translate with --compact");
+    opts.optflag("", "cache-top", "translate with --cache-top");
+    opts.optflag("O", "optimize", "translate with -O");
+    opts.optflag("", "checked", "translate with --checked");
+    opts.optflag("", "tail-calls", "translate with --tail-calls");
+    opts.optflag("i", "inline", "translate with --inline, comparing neither SP, LCL, ARG nor the stack");
+    opts.optopt("s", "steps", "give up after this many VM commands, 10000000 by default", "STEPS");
+    opts.optopt("", "cycles", "give up after this many Hack instructions, 1000000000 by default", "CYCLES");
+    opts.parsing_style(ParsingStyle::FloatingFrees);
+
+    let matches = opts.parse(&args[1..]).expect("Unable to parse arguments.");
+
+    let fuzz_count = matches.opt_str("fuzz").map(|count| count.parse::<u64>().expect("Invalid program count."));
+    if fuzz_count.is_none() && matches.free.len() != 1 {
+        let brief = format!("Usage: {} FILE [options]\n       {} --fuzz COUNT [options]", program, program);
+        println!("{}", opts.usage(&brief));
+        return;
+    }
+
+    // Random programs start in Sys.init, so need the bootstrap code
+    if fuzz_count.is_some() && matches.opt_present("no-bootstrap") {
+        eprintln!("--no-bootstrap cannot be used with --fuzz.");
+        process::exit(1);
+    }
+
+    let options = TranslateOptions {
+        bootstrap: !matches.opt_present("no-bootstrap"),
+        compact: matches.opt_present("compact"),
+        cache_top: matches.opt_present("cache-top"),
+        optimize: matches.opt_present("optimize"),
+        checked: matches.opt_present("checked"),
+        tail_calls: matches.opt_present("tail-calls"),
+        inline: matches.opt_present("inline"),
+        ..TranslateOptions::default()
+    };
+    let max_steps = matches.opt_str("steps").map_or(10_000_000, |steps| steps.parse().expect("Invalid step count."));
+    let max_cycles = matches.opt_str("cycles")
+        .map_or(1_000_000_000, |cycles| cycles.parse().expect("Invalid cycle count."));
+
+    let run = |sources: &[Source]| -> bool {
+        match compare(sources, &options, max_steps, max_cycles) {
+            Ok(ref report) if report.differences.is_empty() => true,
+            Ok(report) => {
+                println!("{} words differ after {} steps and {} cycles:",
+                         report.differences.len(),
+                         report.steps,
+                         report.cycles);
+                for difference in report.differences.iter().take(MAX_SHOWN) {
+                    println!("    {}", difference);
+                }
+                false
+            }
+            Err(message) => {
+                println!("{}", message);
+                false
+            }
+        }
+    };
+
+    let count = match fuzz_count {
+        Some(count) => count,
+        None => {
+            let sources = read_sources(Path::new(&matches.free[0])).expect("Unable to read file.");
+            if !run(&sources) {
+                process::exit(1);
+            }
+            println!("The VM emulator and the Hack CPU agree.");
+            return;
+        }
+    };
+
+    let first_seed = matches.opt_str("seed").map_or(1, |seed| seed.parse().expect("Invalid seed."));
+    let mut failures = 0;
+    for seed in first_seed..first_seed + count {
+        let sources = generate(seed);
+        print!("Seed {}: ", seed);
+        if run(&sources) {
+            println!("agree");
+            continue;
+        }
+        failures += 1;
+        if let Some(ref keep_dir) = matches.opt_str("keep") {
+            let program_dir = Path::new(keep_dir).join(format!("seed{}", seed));
+            fs::create_dir_all(&program_dir).expect("Unable to create directory.");
+            for source in &sources {
+                let mut out_file = File::create(program_dir.join(&source.name)).expect("Unable to create file.");
+                out_file.write_all(source.text.as_bytes()).expect("Unable to write to file.");
+            }
+        }
+    }
+
+    println!("{} of {} programs differ.", failures, count);
+    if failures > 0 {
+        process::exit(1);
+    }
+}
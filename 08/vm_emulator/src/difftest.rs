@@ -0,0 +1,149 @@
+//! Runs a VM program in the VM emulator and, translated and assembled, on
+//! the Hack CPU, then compares the memory both leave behind.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use assembler::variables;
+use hack_emulator::cpu::Cpu;
+use hack_emulator::program::Program as HackProgram;
+use vm_translator::{translate, Source, TranslateOptions};
+use vm_translator::vm_command::{Segment, VmCommand};
+
+use program::Program;
+use vm::{Vm, RAM_SIZE};
+
+const SP: usize = 0;
+// Instructions the Hack ROM holds
+const ROM_SIZE: usize = 32768;
+
+/// Parts of RAM compared, as (name, start, end), where an end of 0 stands
+/// for SP. Statics are compared by name, as -O can leave out some and so
+/// change where the others go.
+const REGIONS: &[(&str, usize, usize)] = &[
+    ("pointers", 0, 5),
+    ("temp", 5, 13),
+    ("stack", 256, 0),
+    ("heap", 2048, 16384),
+    ("screen", 16384, 24576),
+];
+
+/// Parts of RAM compared after inlining, which makes callers' frames
+/// larger and so moves SP, LCL, ARG and everything on the stack
+const INLINED_REGIONS: &[(&str, usize, usize)] = &[
+    ("pointers", 3, 5),
+    ("temp", 5, 13),
+    ("heap", 2048, 16384),
+    ("screen", 16384, 24576),
+];
+
+pub struct Report {
+    pub steps: u64,
+    pub cycles: u64,
+    /// Every word that differs, with the region it is in
+    pub differences: Vec<String>,
+}
+
+/// Runs both until they halt or enter `Sys.halt`. Programs that use the
+/// built-in OS cannot be compared, as the Hack side has no such thing.
+pub fn compare(sources: &[Source],
+               options: &TranslateOptions,
+               max_steps: u64,
+               max_cycles: u64)
+               -> Result<Report, String> {
+    let program = Program::load(sources).map_err(|errors| errors.join("\n"))?;
+    // The built-in Sys.init before `start` only runs with the bootstrap
+    // code, which is refused below
+    for index in program.start..program.commands.len() {
+        if let Some(builtin) = program.builtin(index) {
+            return Err(format!("{}: {} is only built into the VM emulator",
+                               program.location(index),
+                               builtin.name));
+        }
+    }
+    if options.bootstrap && program.start > 0 {
+        return Err("Sys.init is only built into the VM emulator".to_string());
+    }
+
+    let translation = translate(sources, options).map_err(|errors| errors.join("\n"))?;
+    if translation.rom_size() > ROM_SIZE {
+        return Err(format!("The program does not fit in ROM ({} instructions)", translation.rom_size()));
+    }
+    let hack = HackProgram::from_asm(&translation.asm);
+    let halt_address = hack.label_address("Sys.halt");
+    let halt_index = program.functions.get("Sys.halt").cloned();
+
+    let mut vm = Vm::new(program);
+    vm.set_rom_map(&translation.rom_map());
+    vm.step_limit = max_steps;
+    let mut cpu = Cpu::new(hack.rom);
+    if options.bootstrap {
+        vm.bootstrap()?;
+    } else {
+        vm.write(SP as u16, 256);
+        cpu.ram[SP] = 256;
+    }
+
+    while !vm.halted() && Some(vm.pc) != halt_index {
+        if vm.steps >= max_steps {
+            return Err(format!("The VM emulator did not halt within {} steps", max_steps));
+        }
+        vm.step()?;
+    }
+    while !cpu.halted() && Some(cpu.pc) != halt_address {
+        if cpu.cycles >= max_cycles {
+            return Err(format!("The Hack emulator did not halt within {} cycles", max_cycles));
+        }
+        cpu.step();
+    }
+
+    let mut differences = Vec::new();
+    let hack_statics: BTreeMap<&str, u16> = variables(&translation.asm).into_iter().collect();
+    for (name, address) in static_addresses(&vm.program) {
+        if let Some(&hack_address) = hack_statics.get(name.as_str()) {
+            let (value, hack_value) = (vm.read(address), cpu.ram[hack_address as usize]);
+            if value != hack_value {
+                differences.push(format!("statics: {} is {} in the VM emulator but {} on the Hack CPU",
+                                         name,
+                                         value as i16,
+                                         hack_value as i16));
+            }
+        }
+    }
+    let regions = if options.inline { INLINED_REGIONS } else { REGIONS };
+    for &(name, start, end) in regions {
+        // An SP past the end of RAM already shows among the pointers
+        let end = if end == 0 { (vm.ram[SP].max(cpu.ram[SP]) as usize).min(RAM_SIZE) } else { end };
+        for address in start..end {
+            if vm.ram[address] != cpu.ram[address] {
+                differences.push(format!("{}: RAM[{}] is {} in the VM emulator but {} on the Hack CPU",
+                                         name,
+                                         address,
+                                         vm.ram[address] as i16,
+                                         cpu.ram[address] as i16));
+            }
+        }
+    }
+
+    Ok(Report {
+        steps: vm.steps,
+        cycles: cpu.cycles,
+        differences,
+    })
+}
+
+// Each static variable, named like the translator names it, with its RAM
+// address in the VM emulator
+fn static_addresses(program: &Program) -> BTreeMap<String, u16> {
+    program.commands
+        .iter()
+        .enumerate()
+        .filter_map(|(index, command)| match *command {
+            VmCommand::Push(Segment::Static, i) | VmCommand::Pop(Segment::Static, i) => {
+                let stem = Path::new(&program.locations[index].0).file_stem().unwrap().to_str().unwrap();
+                Some((format!("{}.{}", stem, i), program.static_address(index)))
+            }
+            _ => None,
+        })
+        .collect()
+}
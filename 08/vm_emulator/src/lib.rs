@@ -0,0 +1,15 @@
+extern crate assembler;
+extern crate hack_emulator;
+extern crate vm_translator;
+
+pub mod program;
+
+pub mod vm;
+
+pub mod os;
+
+mod font;
+
+pub mod difftest;
+
+pub mod random_program;
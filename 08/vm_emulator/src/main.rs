@@ -0,0 +1,164 @@
+use std::env;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+use std::process;
+
+extern crate getopts;
+use getopts::Options;
+use getopts::ParsingStyle;
+
+extern crate vm_translator;
+use vm_translator::read_sources;
+
+extern crate vm_emulator;
+use vm_emulator::program::Program;
+use vm_emulator::vm::{Vm, RAM_SIZE};
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let program = args[0].clone();
+
+    let mut opts = Options::new();
+    opts.optopt("s", "steps", "stop after this many VM commands", "STEPS");
+    opts.optflag("", "no-bootstrap", "start at the first command instead of calling Sys.init");
+    opts.optmulti("", "set", "set RAM[ADDR] to VALUE before running", "ADDR=VALUE");
+    opts.optmulti("p", "print", "print RAM addresses in LO-HI when stopping", "LO-HI");
+    opts.optopt("", "rom-map", "save return addresses as in ROM, from vm_translator --annotate", "MAPFILE");
+    opts.optopt("", "trace", "write each command run to this file", "TRACEFILE");
+    opts.optopt("k", "keys", "keys the built-in Keyboard.readChar returns, \\n for newline", "TEXT");
+    opts.parsing_style(ParsingStyle::FloatingFrees);
+
+    let matches = opts.parse(&args[1..]).expect("Unable to parse arguments.");
+
+    let in_path = if matches.free.len() == 1 {
+        Path::new(&matches.free[0])
+    } else {
+        let brief = format!("Usage: {} FILE [options]", program);
+        println!("{}", opts.usage(&brief));
+        return;
+    };
+
+    let sources = read_sources(in_path).expect("Unable to read file.");
+    let program = match Program::load(&sources) {
+        Ok(program) => program,
+        Err(errors) => {
+            for error in &errors {
+                eprintln!("{}", error);
+            }
+            process::exit(1);
+        }
+    };
+
+    let mut vm = Vm::new(program);
+    if let Some(map_file) = matches.opt_str("rom-map") {
+        vm.set_rom_map(&read_rom_map(Path::new(&map_file)));
+    }
+    if let Some(keys) = matches.opt_str("keys") {
+        vm.os.keys = keys.replace("\\n", "\n")
+            .chars()
+            .map(|c| if c == '\n' { 128 } else { c as u16 })
+            .collect();
+    }
+    for setting in matches.opt_strs("set") {
+        let (address, value) = parse_setting(&setting);
+        vm.write(address, value);
+    }
+
+    let max_steps = matches.opt_str("steps")
+        .map_or(u64::MAX, |steps| steps.parse().expect("Invalid step count."));
+    vm.step_limit = max_steps;
+
+    let mut tracer = matches.opt_str("trace")
+        .map(|trace_file| BufWriter::new(File::create(trace_file).expect("Unable to create file.")));
+
+    let mut result = if matches.opt_present("no-bootstrap") {
+        Ok(())
+    } else {
+        vm.bootstrap()
+    };
+    while result.is_ok() && vm.steps < max_steps && !vm.halted() {
+        if let Some(ref mut tracer) = tracer {
+            writeln!(tracer,
+                     "{:>10} {:<16} SP={:<5} {}",
+                     vm.steps,
+                     vm.program.location(vm.pc),
+                     vm.read(0),
+                     vm.program.commands[vm.pc])
+                .expect("Unable to write to file.");
+        }
+        result = vm.step();
+    }
+
+    if let Some(ref mut tracer) = tracer {
+        tracer.flush().expect("Unable to write to file.");
+    }
+
+    if !vm.os.output.is_empty() {
+        println!("{}", vm.os.output);
+    }
+
+    match result {
+        Ok(()) if vm.halted() => println!("Halted after {} steps.", vm.steps),
+        Ok(()) => println!("Stopped after {} steps.", vm.steps),
+        Err(ref message) => {
+            println!("Error after {} steps: {}", vm.steps, message);
+            for frame in vm.backtrace() {
+                println!("    in {}", frame);
+            }
+        }
+    }
+
+    for range in matches.opt_strs("print") {
+        let (start, end) = parse_range(&range);
+        for address in start..end {
+            println!("RAM[{}] = {}", address, vm.read(address) as i16);
+        }
+    }
+
+    if result.is_err() {
+        process::exit(1);
+    }
+}
+
+// Reads the `ADDRESS FILE LINE` entries of a ROM map
+fn read_rom_map(path: &Path) -> Vec<(usize, String, usize)> {
+    let in_file = BufReader::new(File::open(path).expect("Unable to find file."));
+
+    in_file.lines()
+        .map(|line| {
+            let line = line.expect("Unable to read file.");
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields[..] {
+                [address, vm_file, vm_line] => {
+                    (address.parse().expect("Invalid ROM map."),
+                     vm_file.to_string(),
+                     vm_line.parse().expect("Invalid ROM map."))
+                }
+                _ => panic!("Invalid ROM map line: {}", line),
+            }
+        })
+        .collect()
+}
+
+// Parses `ADDR=VALUE`, where VALUE may be negative
+fn parse_setting(setting: &str) -> (u16, u16) {
+    let mut fields = setting.splitn(2, '=').map(|field| field.trim());
+
+    match (fields.next().map(str::parse::<u16>), fields.next().map(str::parse::<i16>)) {
+        (Some(Ok(address)), Some(Ok(value))) => (address, value as u16),
+        _ => panic!("Invalid setting: {}", setting),
+    }
+}
+
+// Parses an inclusive `LO-HI` range of RAM addresses into a half-open one
+fn parse_range(range: &str) -> (u16, u16) {
+    let mut bounds = range.splitn(2, '-')
+        .map(|bound| bound.trim().parse::<u16>().ok().filter(|&address| (address as usize) < RAM_SIZE));
+
+    match (bounds.next(), bounds.next()) {
+        (Some(Some(lo)), Some(Some(hi))) if lo <= hi => (lo, hi + 1),
+        (Some(Some(lo)), None) => (lo, lo + 1),
+        _ => panic!("Invalid range: {}", range),
+    }
+}
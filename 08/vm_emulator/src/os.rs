@@ -0,0 +1,553 @@
+//! Built-in Rust versions of the Jack OS, used for every class the program
+//! does not define in a .vm file. They keep their state in `OsState`, and
+//! go through the VM whenever they need another class, so a student-written
+//! class can be tested with the rest of the OS built in.
+
+use std::collections::{BTreeMap, VecDeque};
+
+use font::glyph;
+use vm::Vm;
+
+pub const HEAP_BASE: u16 = 2048;
+pub const HEAP_END: u16 = 16384;
+pub const SCREEN: u16 = 16384;
+pub const KBD: u16 = 24576;
+
+const TEXT_ROWS: u16 = 23;
+const TEXT_COLUMNS: u16 = 64;
+const NEW_LINE: u16 = 128;
+const BACKSPACE: u16 = 129;
+
+// Offsets into a built-in String, which is followed by its characters
+const CAPACITY: u16 = 0;
+const LENGTH: u16 = 1;
+const CHARS: u16 = 2;
+
+/// Source of `Sys.init`, which has to call into the program and so cannot
+/// be written in Rust
+pub const SYS_INIT: &str = "function Sys.init 0\n\
+                            call Memory.init 0\n\
+                            pop temp 0\n\
+                            call Math.init 0\n\
+                            pop temp 0\n\
+                            call Screen.init 0\n\
+                            pop temp 0\n\
+                            call Output.init 0\n\
+                            pop temp 0\n\
+                            call Keyboard.init 0\n\
+                            pop temp 0\n\
+                            call Main.main 0\n\
+                            pop temp 0\n\
+                            call Sys.halt 0\n\
+                            pop temp 0\n\
+                            push constant 0\n\
+                            return\n";
+
+pub struct Builtin {
+    pub name: &'static str,
+    pub args: u16,
+    pub function: fn(&mut Vm, &[u16]) -> Result<u16, String>,
+}
+
+/// State of the built-in classes
+pub struct OsState {
+    /// Free heap blocks as (start, length), in address order
+    free: Vec<(u16, u16)>,
+    /// Length of each allocated block by start
+    allocated: BTreeMap<u16, u16>,
+    black: bool,
+    row: u16,
+    column: u16,
+    /// Keys `Keyboard.readChar` returns, in order
+    pub keys: VecDeque<u16>,
+    /// Everything printed through `Output`, as text
+    pub output: String,
+}
+
+impl OsState {
+    pub fn new() -> Self {
+        OsState {
+            free: vec![(HEAP_BASE, HEAP_END - HEAP_BASE)],
+            allocated: BTreeMap::new(),
+            black: true,
+            row: 0,
+            column: 0,
+            keys: VecDeque::new(),
+            output: String::new(),
+        }
+    }
+}
+
+impl Default for OsState {
+    fn default() -> Self {
+        OsState::new()
+    }
+}
+
+pub const BUILTINS: &[Builtin] = &[
+    Builtin { name: "Array.new", args: 1, function: array_new },
+    Builtin { name: "Array.dispose", args: 1, function: dispose },
+    Builtin { name: "Keyboard.init", args: 0, function: nothing },
+    Builtin { name: "Keyboard.keyPressed", args: 0, function: keyboard_key_pressed },
+    Builtin { name: "Keyboard.readChar", args: 0, function: keyboard_read_char },
+    Builtin { name: "Keyboard.readLine", args: 1, function: keyboard_read_line },
+    Builtin { name: "Keyboard.readInt", args: 1, function: keyboard_read_int },
+    Builtin { name: "Math.init", args: 0, function: nothing },
+    Builtin { name: "Math.abs", args: 1, function: math_abs },
+    Builtin { name: "Math.multiply", args: 2, function: math_multiply },
+    Builtin { name: "Math.divide", args: 2, function: math_divide },
+    Builtin { name: "Math.min", args: 2, function: math_min },
+    Builtin { name: "Math.max", args: 2, function: math_max },
+    Builtin { name: "Math.sqrt", args: 1, function: math_sqrt },
+    Builtin { name: "Memory.init", args: 0, function: memory_init },
+    Builtin { name: "Memory.peek", args: 1, function: memory_peek },
+    Builtin { name: "Memory.poke", args: 2, function: memory_poke },
+    Builtin { name: "Memory.alloc", args: 1, function: memory_alloc },
+    Builtin { name: "Memory.deAlloc", args: 1, function: memory_de_alloc },
+    Builtin { name: "Output.init", args: 0, function: output_init },
+    Builtin { name: "Output.moveCursor", args: 2, function: output_move_cursor },
+    Builtin { name: "Output.printChar", args: 1, function: output_print_char },
+    Builtin { name: "Output.printString", args: 1, function: output_print_string },
+    Builtin { name: "Output.printInt", args: 1, function: output_print_int },
+    Builtin { name: "Output.println", args: 0, function: output_println },
+    Builtin { name: "Output.backSpace", args: 0, function: output_back_space },
+    Builtin { name: "Screen.init", args: 0, function: screen_init },
+    Builtin { name: "Screen.clearScreen", args: 0, function: screen_clear_screen },
+    Builtin { name: "Screen.setColor", args: 1, function: screen_set_color },
+    Builtin { name: "Screen.drawPixel", args: 2, function: screen_draw_pixel },
+    Builtin { name: "Screen.drawLine", args: 4, function: screen_draw_line },
+    Builtin { name: "Screen.drawRectangle", args: 4, function: screen_draw_rectangle },
+    Builtin { name: "Screen.drawCircle", args: 3, function: screen_draw_circle },
+    Builtin { name: "String.new", args: 1, function: string_new },
+    Builtin { name: "String.dispose", args: 1, function: dispose },
+    Builtin { name: "String.length", args: 1, function: string_length },
+    Builtin { name: "String.charAt", args: 2, function: string_char_at },
+    Builtin { name: "String.setCharAt", args: 3, function: string_set_char_at },
+    Builtin { name: "String.appendChar", args: 2, function: string_append_char },
+    Builtin { name: "String.eraseLastChar", args: 1, function: string_erase_last_char },
+    Builtin { name: "String.intValue", args: 1, function: string_int_value },
+    Builtin { name: "String.setInt", args: 2, function: string_set_int },
+    Builtin { name: "String.newLine", args: 0, function: string_new_line },
+    Builtin { name: "String.backSpace", args: 0, function: string_back_space },
+    Builtin { name: "String.doubleQuote", args: 0, function: string_double_quote },
+    Builtin { name: "Sys.halt", args: 0, function: sys_halt },
+    Builtin { name: "Sys.error", args: 1, function: sys_error },
+    Builtin { name: "Sys.wait", args: 1, function: nothing },
+];
+
+/// Index into `BUILTINS` of the function called `name`
+pub fn find(name: &str) -> Option<usize> {
+    BUILTINS.iter().position(|builtin| builtin.name == name)
+}
+
+fn nothing(_: &mut Vm, _: &[u16]) -> Result<u16, String> {
+    Ok(0)
+}
+
+fn array_new(vm: &mut Vm, args: &[u16]) -> Result<u16, String> {
+    if args[0] as i16 <= 0 {
+        return Err(format!("Array.new: size {} is not positive", args[0] as i16));
+    }
+    vm.invoke("Memory.alloc", &[args[0]])
+}
+
+// `Array.dispose` and `String.dispose`, as both are one heap block
+fn dispose(vm: &mut Vm, args: &[u16]) -> Result<u16, String> {
+    vm.invoke("Memory.deAlloc", &[args[0]])
+}
+
+fn keyboard_key_pressed(vm: &mut Vm, _: &[u16]) -> Result<u16, String> {
+    Ok(vm.read(KBD))
+}
+
+// Takes the next key given to the emulator, as there is no one to press it
+fn keyboard_read_char(vm: &mut Vm, _: &[u16]) -> Result<u16, String> {
+    let key = vm.os.keys.pop_front().ok_or("Keyboard.readChar: no keyboard input is left")?;
+    vm.invoke("Output.printChar", &[key])?;
+    Ok(key)
+}
+
+fn keyboard_read_line(vm: &mut Vm, args: &[u16]) -> Result<u16, String> {
+    vm.invoke("Output.printString", &[args[0]])?;
+    let line = vm.invoke("String.new", &[64])?;
+
+    loop {
+        match keyboard_read_char(vm, &[])? {
+            NEW_LINE => return Ok(line),
+            BACKSPACE => {
+                if vm.invoke("String.length", &[line])? > 0 {
+                    vm.invoke("String.eraseLastChar", &[line])?;
+                }
+            }
+            key => {
+                vm.invoke("String.appendChar", &[line, key])?;
+            }
+        }
+    }
+}
+
+fn keyboard_read_int(vm: &mut Vm, args: &[u16]) -> Result<u16, String> {
+    let line = keyboard_read_line(vm, args)?;
+    let value = vm.invoke("String.intValue", &[line])?;
+    vm.invoke("String.dispose", &[line])?;
+    Ok(value)
+}
+
+fn math_abs(_: &mut Vm, args: &[u16]) -> Result<u16, String> {
+    Ok((args[0] as i16).wrapping_abs() as u16)
+}
+
+fn math_multiply(_: &mut Vm, args: &[u16]) -> Result<u16, String> {
+    Ok(args[0].wrapping_mul(args[1]))
+}
+
+fn math_divide(_: &mut Vm, args: &[u16]) -> Result<u16, String> {
+    if args[1] == 0 {
+        return Err("Math.divide: division by zero".to_string());
+    }
+    Ok((args[0] as i16).wrapping_div(args[1] as i16) as u16)
+}
+
+fn math_min(_: &mut Vm, args: &[u16]) -> Result<u16, String> {
+    Ok((args[0] as i16).min(args[1] as i16) as u16)
+}
+
+fn math_max(_: &mut Vm, args: &[u16]) -> Result<u16, String> {
+    Ok((args[0] as i16).max(args[1] as i16) as u16)
+}
+
+fn math_sqrt(_: &mut Vm, args: &[u16]) -> Result<u16, String> {
+    let x = args[0] as i16;
+    if x < 0 {
+        return Err(format!("Math.sqrt: {} is negative", x));
+    }
+    Ok(f64::from(x).sqrt() as u16)
+}
+
+fn memory_init(vm: &mut Vm, _: &[u16]) -> Result<u16, String> {
+    vm.os.free = vec![(HEAP_BASE, HEAP_END - HEAP_BASE)];
+    vm.os.allocated.clear();
+    Ok(0)
+}
+
+fn memory_peek(vm: &mut Vm, args: &[u16]) -> Result<u16, String> {
+    Ok(vm.read(args[0]))
+}
+
+fn memory_poke(vm: &mut Vm, args: &[u16]) -> Result<u16, String> {
+    vm.write(args[0], args[1]);
+    Ok(0)
+}
+
+// First fit, keeping the free list in address order
+fn memory_alloc(vm: &mut Vm, args: &[u16]) -> Result<u16, String> {
+    let size = args[0] as i16;
+    if size < 0 {
+        return Err(format!("Memory.alloc: size {} is negative", size));
+    }
+    let size = (size as u16).max(1);
+
+    let free = &mut vm.os.free;
+    let index = free.iter()
+        .position(|&(_, length)| length >= size)
+        .ok_or_else(|| format!("Memory.alloc: no free block of {} words is left", size))?;
+    let start = free[index].0;
+    if free[index].1 == size {
+        free.remove(index);
+    } else {
+        free[index] = (start + size, free[index].1 - size);
+    }
+
+    vm.os.allocated.insert(start, size);
+    Ok(start)
+}
+
+fn memory_de_alloc(vm: &mut Vm, args: &[u16]) -> Result<u16, String> {
+    let start = args[0];
+    let length = vm.os.allocated
+        .remove(&start)
+        .ok_or_else(|| format!("Memory.deAlloc: {} is not an allocated block", start))?;
+
+    // Merge with the neighbouring free blocks
+    let free = &mut vm.os.free;
+    let index = free.iter().position(|&(block, _)| block > start).unwrap_or(free.len());
+    free.insert(index, (start, length));
+    if index + 1 < free.len() && start + length == free[index + 1].0 {
+        free[index].1 += free.remove(index + 1).1;
+    }
+    if index > 0 && free[index - 1].0 + free[index - 1].1 == start {
+        free[index - 1].1 += free.remove(index).1;
+    }
+    Ok(0)
+}
+
+fn output_init(vm: &mut Vm, _: &[u16]) -> Result<u16, String> {
+    vm.os.row = 0;
+    vm.os.column = 0;
+    Ok(0)
+}
+
+fn output_move_cursor(vm: &mut Vm, args: &[u16]) -> Result<u16, String> {
+    let (row, column) = (args[0], args[1]);
+    if row >= TEXT_ROWS || column >= TEXT_COLUMNS {
+        return Err(format!("Output.moveCursor: ({}, {}) is off the screen", row as i16, column as i16));
+    }
+    vm.os.row = row;
+    vm.os.column = column;
+    draw_char(vm, b' ' as u16);
+    Ok(0)
+}
+
+fn output_print_char(vm: &mut Vm, args: &[u16]) -> Result<u16, String> {
+    match args[0] {
+        NEW_LINE => return output_println(vm, &[]),
+        BACKSPACE => return output_back_space(vm, &[]),
+        c => {
+            draw_char(vm, c);
+            vm.os.output.push(if (32..=126).contains(&c) { c as u8 as char } else { '\u{25a0}' });
+        }
+    }
+
+    vm.os.column += 1;
+    if vm.os.column == TEXT_COLUMNS {
+        output_println(vm, &[])?;
+    }
+    Ok(0)
+}
+
+fn output_print_string(vm: &mut Vm, args: &[u16]) -> Result<u16, String> {
+    let length = vm.invoke("String.length", &[args[0]])?;
+    for i in 0..length {
+        let c = vm.invoke("String.charAt", &[args[0], i])?;
+        output_print_char(vm, &[c])?;
+    }
+    Ok(0)
+}
+
+fn output_print_int(vm: &mut Vm, args: &[u16]) -> Result<u16, String> {
+    for c in (args[0] as i16).to_string().bytes() {
+        output_print_char(vm, &[c as u16])?;
+    }
+    Ok(0)
+}
+
+fn output_println(vm: &mut Vm, _: &[u16]) -> Result<u16, String> {
+    vm.os.column = 0;
+    vm.os.row = (vm.os.row + 1) % TEXT_ROWS;
+    vm.os.output.push('\n');
+    Ok(0)
+}
+
+fn output_back_space(vm: &mut Vm, _: &[u16]) -> Result<u16, String> {
+    if vm.os.column > 0 {
+        vm.os.column -= 1;
+    } else {
+        vm.os.column = TEXT_COLUMNS - 1;
+        vm.os.row = (vm.os.row + TEXT_ROWS - 1) % TEXT_ROWS;
+    }
+    draw_char(vm, b' ' as u16);
+    vm.os.output.pop();
+    Ok(0)
+}
+
+// Draws `c` over the character cell under the cursor
+fn draw_char(vm: &mut Vm, c: u16) {
+    let bitmap = glyph(c);
+    let (shift, keep) = if vm.os.column.is_multiple_of(2) { (0, 0xFF00) } else { (8, 0x00FF) };
+
+    for (dy, &bits) in bitmap.iter().enumerate() {
+        let y = vm.os.row * 11 + dy as u16;
+        let address = SCREEN + y * 32 + vm.os.column / 2;
+        let word = (vm.read(address) & keep) | (u16::from(bits) << shift);
+        vm.write(address, word);
+    }
+}
+
+fn screen_init(vm: &mut Vm, _: &[u16]) -> Result<u16, String> {
+    vm.os.black = true;
+    Ok(0)
+}
+
+fn screen_clear_screen(vm: &mut Vm, _: &[u16]) -> Result<u16, String> {
+    for address in SCREEN..KBD {
+        vm.write(address, 0);
+    }
+    Ok(0)
+}
+
+fn screen_set_color(vm: &mut Vm, args: &[u16]) -> Result<u16, String> {
+    vm.os.black = args[0] != 0;
+    Ok(0)
+}
+
+fn screen_draw_pixel(vm: &mut Vm, args: &[u16]) -> Result<u16, String> {
+    draw_pixel(vm, args[0] as i16, args[1] as i16)?;
+    Ok(0)
+}
+
+fn draw_pixel(vm: &mut Vm, x: i16, y: i16) -> Result<(), String> {
+    if !(0..=511).contains(&x) || !(0..=255).contains(&y) {
+        return Err(format!("Screen: ({}, {}) is off the screen", x, y));
+    }
+    let address = SCREEN + y as u16 * 32 + x as u16 / 16;
+    let mask = 1 << (x & 15);
+    let word = if vm.os.black {
+        vm.read(address) | mask
+    } else {
+        vm.read(address) & !mask
+    };
+    vm.write(address, word);
+    Ok(())
+}
+
+// The book's algorithm, stepping along whichever of x and y lags behind
+fn screen_draw_line(vm: &mut Vm, args: &[u16]) -> Result<u16, String> {
+    let (mut x1, mut y1) = (args[0] as i16 as i32, args[1] as i16 as i32);
+    let (mut x2, mut y2) = (args[2] as i16 as i32, args[3] as i16 as i32);
+    if x2 < x1 {
+        std::mem::swap(&mut x1, &mut x2);
+        std::mem::swap(&mut y1, &mut y2);
+    }
+
+    let dx = x2 - x1;
+    let dy = (y2 - y1).abs();
+    let step = if y2 < y1 { -1 } else { 1 };
+    let (mut a, mut b, mut difference) = (0, 0, 0);
+    while a <= dx && b <= dy {
+        draw_pixel(vm, (x1 + a) as i16, (y1 + b * step) as i16)?;
+        if dy == 0 || (dx != 0 && difference < 0) {
+            a += 1;
+            difference += dy;
+        } else {
+            b += 1;
+            difference -= dx;
+        }
+    }
+    Ok(0)
+}
+
+fn screen_draw_rectangle(vm: &mut Vm, args: &[u16]) -> Result<u16, String> {
+    let (x1, y1, x2, y2) = (args[0] as i16, args[1] as i16, args[2] as i16, args[3] as i16);
+    for y in y1..=y2 {
+        for x in x1..=x2 {
+            draw_pixel(vm, x, y)?;
+        }
+    }
+    Ok(0)
+}
+
+fn screen_draw_circle(vm: &mut Vm, args: &[u16]) -> Result<u16, String> {
+    let (x, y, r) = (args[0] as i16 as i32, args[1] as i16 as i32, args[2] as i16 as i32);
+    if !(0..=181).contains(&r) {
+        return Err(format!("Screen.drawCircle: radius {} is out of range", r));
+    }
+    for dy in -r..=r {
+        let half = f64::from(r * r - dy * dy).sqrt() as i32;
+        for dx in -half..=half {
+            draw_pixel(vm, (x + dx) as i16, (y + dy) as i16)?;
+        }
+    }
+    Ok(0)
+}
+
+fn string_new(vm: &mut Vm, args: &[u16]) -> Result<u16, String> {
+    let capacity = args[0] as i16;
+    if capacity < 0 {
+        return Err(format!("String.new: length {} is negative", capacity));
+    }
+    let this = vm.invoke("Memory.alloc", &[CHARS + capacity as u16])?;
+    vm.write(this + CAPACITY, capacity as u16);
+    vm.write(this + LENGTH, 0);
+    Ok(this)
+}
+
+fn string_length(vm: &mut Vm, args: &[u16]) -> Result<u16, String> {
+    Ok(vm.read(args[0] + LENGTH))
+}
+
+fn string_char_at(vm: &mut Vm, args: &[u16]) -> Result<u16, String> {
+    let (this, j) = (args[0], args[1]);
+    if j >= vm.read(this + LENGTH) {
+        return Err(format!("String.charAt: index {} is out of range", j as i16));
+    }
+    Ok(vm.read(this + CHARS + j))
+}
+
+fn string_set_char_at(vm: &mut Vm, args: &[u16]) -> Result<u16, String> {
+    let (this, j, c) = (args[0], args[1], args[2]);
+    if j >= vm.read(this + LENGTH) {
+        return Err(format!("String.setCharAt: index {} is out of range", j as i16));
+    }
+    vm.write(this + CHARS + j, c);
+    Ok(0)
+}
+
+fn string_append_char(vm: &mut Vm, args: &[u16]) -> Result<u16, String> {
+    let (this, c) = (args[0], args[1]);
+    let length = vm.read(this + LENGTH);
+    if length >= vm.read(this + CAPACITY) {
+        return Err("String.appendChar: the string is full".to_string());
+    }
+    vm.write(this + CHARS + length, c);
+    vm.write(this + LENGTH, length + 1);
+    Ok(this)
+}
+
+fn string_erase_last_char(vm: &mut Vm, args: &[u16]) -> Result<u16, String> {
+    let this = args[0];
+    let length = vm.read(this + LENGTH);
+    if length == 0 {
+        return Err("String.eraseLastChar: the string is empty".to_string());
+    }
+    vm.write(this + LENGTH, length - 1);
+    Ok(0)
+}
+
+// Digits up to the first other character, after an optional minus sign
+fn string_int_value(vm: &mut Vm, args: &[u16]) -> Result<u16, String> {
+    let this = args[0];
+    let length = vm.read(this + LENGTH);
+    let chars: Vec<u16> = (0..length).map(|j| vm.read(this + CHARS + j)).collect();
+
+    let negative = chars.first() == Some(&(b'-' as u16));
+    let value = chars[negative as usize..]
+        .iter()
+        .take_while(|&&c| (b'0' as u16..=b'9' as u16).contains(&c))
+        .fold(0i16, |value, &c| value.wrapping_mul(10).wrapping_add((c - b'0' as u16) as i16));
+
+    Ok(if negative { value.wrapping_neg() } else { value } as u16)
+}
+
+fn string_set_int(vm: &mut Vm, args: &[u16]) -> Result<u16, String> {
+    let (this, number) = (args[0], args[1] as i16);
+    let digits = number.to_string();
+    if digits.len() as u16 > vm.read(this + CAPACITY) {
+        return Err(format!("String.setInt: {} does not fit in the string", number));
+    }
+    for (j, c) in digits.bytes().enumerate() {
+        vm.write(this + CHARS + j as u16, c as u16);
+    }
+    vm.write(this + LENGTH, digits.len() as u16);
+    Ok(0)
+}
+
+fn string_new_line(_: &mut Vm, _: &[u16]) -> Result<u16, String> {
+    Ok(NEW_LINE)
+}
+
+fn string_back_space(_: &mut Vm, _: &[u16]) -> Result<u16, String> {
+    Ok(BACKSPACE)
+}
+
+fn string_double_quote(_: &mut Vm, _: &[u16]) -> Result<u16, String> {
+    Ok(b'"' as u16)
+}
+
+fn sys_halt(vm: &mut Vm, _: &[u16]) -> Result<u16, String> {
+    vm.stopped = true;
+    Ok(0)
+}
+
+fn sys_error(_: &mut Vm, args: &[u16]) -> Result<u16, String> {
+    Err(format!("Sys.error: ERR{}", args[0] as i16))
+}
@@ -0,0 +1,179 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use std::slice;
+
+use os;
+use vm_translator::{parse_sources, Source};
+use vm_translator::vm_command::{Segment, VmCommand};
+
+/// RAM address of the first static variable, as the assembler allocates them
+const STATIC_BASE: u16 = 16;
+
+/// The .vm files of a program linked into one list of commands, in the
+/// order `vm_translator` writes them. Calls to classes no file defines go
+/// to the built-in OS, and a built-in `Sys.init` comes before the
+/// program's commands when there is no `Sys` class.
+pub struct Program {
+    pub commands: Vec<VmCommand>,
+    /// Index of the program's first command, after any built-in code
+    pub start: usize,
+    /// .vm file name and line of each command
+    pub locations: Vec<(String, usize)>,
+    /// Index of the command each function starts at
+    pub functions: BTreeMap<String, usize>,
+    // Command each `goto`, `if-goto` and `call` continues at, if defined
+    targets: Vec<Option<usize>>,
+    // Built-in function each `call` goes to, if any
+    builtins: Vec<Option<usize>>,
+    // Classes some file defines a function of
+    classes: BTreeSet<String>,
+    // RAM address of each `static` access
+    statics: Vec<u16>,
+}
+
+impl Program {
+    pub fn load(sources: &[Source]) -> Result<Program, Vec<String>> {
+        let mut parsed = parse_sources(sources)?;
+
+        // A class is built in unless some file defines a function of it
+        let classes: BTreeSet<String> = parsed.iter()
+            .flat_map(|commands| commands.iter())
+            .filter_map(|(_, command)| match *command {
+                VmCommand::Function(ref name, _) => Some(class_of(name).to_string()),
+                _ => None,
+            })
+            .collect();
+
+        let mut sources = sources.iter().collect::<Vec<_>>();
+        let builtin_sys = Source {
+            name: "Sys.vm".to_string(),
+            text: os::SYS_INIT.to_string(),
+        };
+        if !classes.contains("Sys") {
+            parsed.insert(0, parse_sources(slice::from_ref(&builtin_sys)).unwrap().remove(0));
+            sources.insert(0, &builtin_sys);
+        }
+        let start = if classes.contains("Sys") { 0 } else { parsed[0].len() };
+
+        let mut commands = Vec::new();
+        let mut locations = Vec::new();
+        let mut statics = Vec::new();
+        let mut static_addresses = BTreeMap::new();
+        // Labels are named after their function, like `Main.loop$WHILE0`
+        let mut labels = BTreeMap::new();
+        let mut label_names = Vec::new();
+        let mut function_name = String::new();
+
+        for (source, file_commands) in sources.iter().zip(parsed) {
+            let path = Path::new(&source.name);
+            let vm_file = path.file_name().unwrap().to_str().unwrap();
+            let stem = path.file_stem().unwrap().to_str().unwrap();
+
+            for (line, command) in file_commands {
+                let index = commands.len();
+                match command {
+                    VmCommand::Function(ref name, _) => {
+                        function_name = name.clone();
+                        labels.entry(name.clone()).or_insert(index);
+                    }
+                    VmCommand::Label(ref label) => {
+                        labels.entry(format!("{}${}", function_name, label)).or_insert(index);
+                    }
+                    _ => (),
+                }
+
+                let target = match command {
+                    VmCommand::Goto(ref label) | VmCommand::IfGoto(ref label) => {
+                        Some(format!("{}${}", function_name, label))
+                    }
+                    VmCommand::Call(ref name, _) => Some(name.clone()),
+                    _ => None,
+                };
+                // The assembler numbers variables in order of first use
+                let address = match command {
+                    VmCommand::Push(Segment::Static, i) | VmCommand::Pop(Segment::Static, i) => {
+                        let next = STATIC_BASE + static_addresses.len() as u16;
+                        *static_addresses.entry(format!("{}.{}", stem, i)).or_insert(next)
+                    }
+                    _ => 0,
+                };
+
+                label_names.push(target);
+                statics.push(address);
+                locations.push((vm_file.to_string(), line));
+                commands.push(command);
+            }
+        }
+
+        let functions = commands.iter()
+            .filter_map(|command| match *command {
+                VmCommand::Function(ref name, _) => Some((name.clone(), labels[name])),
+                _ => None,
+            })
+            .collect();
+        let targets: Vec<Option<usize>> = label_names.iter()
+            .map(|name| name.as_ref().and_then(|name| labels.get(name).cloned()))
+            .collect();
+        let builtins = commands.iter()
+            .map(|command| match *command {
+                VmCommand::Call(ref name, _) if !classes.contains(class_of(name)) => os::find(name),
+                _ => None,
+            })
+            .collect();
+
+        Ok(Program {
+            commands,
+            start,
+            locations,
+            functions,
+            targets,
+            builtins,
+            classes,
+            statics,
+        })
+    }
+
+    /// Command the `goto`, `if-goto` or `call` at `index` continues at,
+    /// `None` if its label or function is not defined
+    pub fn target(&self, index: usize) -> Option<usize> {
+        self.targets[index]
+    }
+
+    /// Built-in function the `call` at `index` goes to, if any
+    pub fn builtin(&self, index: usize) -> Option<&'static os::Builtin> {
+        self.builtins[index].map(|builtin| &os::BUILTINS[builtin])
+    }
+
+    /// Built-in function called `name`, unless the program defines its class
+    pub fn builtin_named(&self, name: &str) -> Option<&'static os::Builtin> {
+        if self.classes.contains(class_of(name)) {
+            return None;
+        }
+        os::find(name).map(|builtin| &os::BUILTINS[builtin])
+    }
+
+    /// RAM address of the `static` variable accessed at `index`
+    pub fn static_address(&self, index: usize) -> u16 {
+        self.statics[index]
+    }
+
+    /// `file:line` of the command at `index`, for diagnostics
+    pub fn location(&self, index: usize) -> String {
+        let (ref file, line) = self.locations[index];
+        format!("{}:{}", file, line)
+    }
+
+    /// Function the command at `index` belongs to, if any
+    pub fn function_at(&self, index: usize) -> Option<&str> {
+        self.functions
+            .iter()
+            .filter(|&(_, &start)| start <= index)
+            .max_by_key(|&(_, &start)| start)
+            .map(|(name, _)| name.as_str())
+    }
+}
+
+// `Math` for `Math.multiply`
+fn class_of(function: &str) -> &str {
+    function.split('.').next().unwrap()
+}
@@ -0,0 +1,295 @@
+//! Random well-formed VM programs for `vm_difftest --fuzz`. Functions only
+//! call functions defined after them and loops only run a few times, so
+//! every program halts.
+
+use vm_translator::Source;
+
+const CLASSES: [&str; 2] = ["Main", "Util"];
+const BINARY_OPS: [&str; 11] = ["add", "sub", "and", "or", "eq", "gt", "lt", "mul", "div", "shl", "shr"];
+const MAX_FUNCTIONS: u64 = 5;
+// Call sites in each function
+const MAX_CALLS: u32 = 2;
+// Base address of the heap words `this` and `that` point into
+const HEAP: u16 = 3000;
+
+/// xorshift64*, so a seed always gives the same program
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1 }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// A number in `0..n`
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+
+    pub fn chance(&mut self, percent: u64) -> bool {
+        self.below(100) < percent
+    }
+}
+
+struct Function {
+    name: String,
+    args: u64,
+    // Locals besides the loop counter, which comes last
+    locals: u64,
+}
+
+struct Generator {
+    rng: Rng,
+    functions: Vec<Function>,
+    // Function being written, `functions.len()` for Sys.init
+    current: usize,
+    calls_left: u32,
+    in_loop: bool,
+    labels: usize,
+    out: String,
+}
+
+/// Generates a program of `Main.vm`, `Sys.vm` and maybe `Util.vm`, in the
+/// order `read_sources` gives them. `Sys.init` calls into the other
+/// functions, keeps results in statics and then spins on a `goto`.
+pub fn generate(seed: u64) -> Vec<Source> {
+    let mut rng = Rng::new(seed);
+    let count = 1 + rng.below(MAX_FUNCTIONS) as usize;
+    let functions = (0..count)
+        .map(|i| {
+            Function {
+                name: format!("{}.f{}", CLASSES[i % 2], i),
+                args: rng.below(4),
+                locals: 1 + rng.below(3),
+            }
+        })
+        .collect();
+    let mut generator = Generator {
+        rng,
+        functions,
+        current: 0,
+        calls_left: 0,
+        in_loop: false,
+        labels: 0,
+        out: String::new(),
+    };
+
+    let mut texts = vec![String::new(); CLASSES.len()];
+    for i in 0..count {
+        generator.function(i);
+        texts[i % 2].push_str(&generator.out);
+        generator.out.clear();
+    }
+    generator.sys_init();
+
+    let mut sources = vec![
+        Source { name: "Main.vm".to_string(), text: texts[0].clone() },
+        Source { name: "Sys.vm".to_string(), text: generator.out },
+    ];
+    if count > 1 {
+        sources.push(Source { name: "Util.vm".to_string(), text: texts[1].clone() });
+    }
+    sources
+}
+
+impl Generator {
+    fn function(&mut self, index: usize) {
+        self.current = index;
+        self.calls_left = MAX_CALLS;
+        let header = format!("function {} {}", self.functions[index].name, self.functions[index].locals + 1);
+        self.emit(&header);
+        self.set_pointers();
+
+        let statements = 1 + self.rng.below(4);
+        for _ in 0..statements {
+            self.statement(2);
+        }
+        self.expression(3);
+        self.emit("return");
+    }
+
+    fn sys_init(&mut self) {
+        self.current = self.functions.len();
+        self.calls_left = MAX_CALLS + 1;
+        self.emit("function Sys.init 2");
+        self.set_pointers();
+
+        // Always reach the first function, whatever else happens
+        self.call(0);
+        self.emit("pop static 0");
+        let statements = 1 + self.rng.below(3);
+        for _ in 0..statements {
+            self.statement(1);
+        }
+        self.emit("label HALT");
+        self.emit("goto HALT");
+    }
+
+    // Points `this` and `that` at heap words of the function's own
+    fn set_pointers(&mut self) {
+        let base = HEAP + 40 * self.current as u16;
+        self.emit(&format!("push constant {}", base));
+        self.emit("pop pointer 0");
+        self.emit(&format!("push constant {}", base + 20));
+        self.emit("pop pointer 1");
+    }
+
+    fn statement(&mut self, depth: u32) {
+        match self.rng.below(10) {
+            4 | 5 if depth > 0 => self.if_else(depth),
+            6 if depth > 0 && !self.in_loop => self.counted_loop(depth),
+            7 if self.calls_left > 0 => {
+                if let Some(callee) = self.callee() {
+                    self.call(callee);
+                    self.emit("pop temp 0");
+                }
+            }
+            8 => {
+                let address = HEAP + self.rng.below(300) as u16;
+                self.emit(&format!("push constant {}", address));
+                self.emit("pop pointer 1");
+            }
+            _ => {
+                self.expression(3);
+                let target = self.target();
+                self.emit(&format!("pop {}", target));
+            }
+        }
+    }
+
+    fn if_else(&mut self, depth: u32) {
+        let (then_label, end_label) = (self.label(), self.label());
+        self.expression(2);
+        self.emit(&format!("if-goto {}", then_label));
+        self.statement(depth - 1);
+        self.emit(&format!("goto {}", end_label));
+        self.emit(&format!("label {}", then_label));
+        self.statement(depth - 1);
+        // Sys.init has nowhere to return to
+        if self.current < self.functions.len() && self.rng.chance(20) {
+            self.expression(2);
+            self.emit("return");
+        }
+        self.emit(&format!("label {}", end_label));
+    }
+
+    // Runs its body one to three times, counting down in the last local
+    fn counted_loop(&mut self, depth: u32) {
+        let counter = match self.functions.get(self.current) {
+            Some(function) => function.locals,
+            None => 1,
+        };
+        let top = self.label();
+        let times = 1 + self.rng.below(3);
+        self.emit(&format!("push constant {}", times));
+        self.emit(&format!("pop local {}", counter));
+        self.emit(&format!("label {}", top));
+
+        self.in_loop = true;
+        let statements = 1 + self.rng.below(3);
+        for _ in 0..statements {
+            self.statement(depth - 1);
+        }
+        self.in_loop = false;
+
+        self.emit(&format!("push local {}", counter));
+        self.emit("push constant 1");
+        self.emit("sub");
+        self.emit(&format!("pop local {}", counter));
+        self.emit(&format!("push local {}", counter));
+        self.emit(&format!("if-goto {}", top));
+    }
+
+    // Pushes one value
+    fn expression(&mut self, depth: u32) {
+        if depth == 0 || self.rng.chance(30) {
+            let source = self.source();
+            self.emit(&format!("push {}", source));
+            return;
+        }
+        match self.rng.below(10) {
+            0 => {
+                self.expression(depth - 1);
+                let op = if self.rng.chance(50) { "neg" } else { "not" };
+                self.emit(op);
+            }
+            1 if self.calls_left > 0 => {
+                match self.callee() {
+                    Some(callee) => self.call(callee),
+                    None => self.expression(depth - 1),
+                }
+            }
+            _ => {
+                self.expression(depth - 1);
+                self.expression(depth - 1);
+                let op = BINARY_OPS[self.rng.below(BINARY_OPS.len() as u64) as usize];
+                // An odd divisor is never 0, which --checked code halts on
+                if op == "div" {
+                    self.emit("push constant 1");
+                    self.emit("or");
+                }
+                self.emit(op);
+            }
+        }
+    }
+
+    fn call(&mut self, callee: usize) {
+        self.calls_left = self.calls_left.saturating_sub(1);
+        for _ in 0..self.functions[callee].args {
+            self.expression(1);
+        }
+        let call = format!("call {} {}", self.functions[callee].name, self.functions[callee].args);
+        self.emit(&call);
+    }
+
+    // A function defined after the current one, so calls never recurse
+    fn callee(&mut self) -> Option<usize> {
+        let first = if self.current < self.functions.len() { self.current + 1 } else { 0 };
+        if first >= self.functions.len() {
+            return None;
+        }
+        Some(first + self.rng.below((self.functions.len() - first) as u64) as usize)
+    }
+
+    fn source(&mut self) -> String {
+        match self.rng.below(8) {
+            0 => format!("constant {}", self.rng.below(32768)),
+            1 | 2 => format!("constant {}", self.rng.below(10)),
+            _ => self.target(),
+        }
+    }
+
+    // A segment and index that may be both read and written
+    fn target(&mut self) -> String {
+        let (args, locals) = match self.functions.get(self.current) {
+            Some(function) => (function.args, function.locals),
+            None => (0, 1),
+        };
+        match self.rng.below(6) {
+            0 => format!("local {}", self.rng.below(locals)),
+            1 if args > 0 => format!("argument {}", self.rng.below(args)),
+            2 => format!("static {}", self.rng.below(4)),
+            3 => format!("temp {}", self.rng.below(8)),
+            4 => format!("this {}", self.rng.below(8)),
+            _ => format!("that {}", self.rng.below(8)),
+        }
+    }
+
+    fn label(&mut self) -> String {
+        self.labels += 1;
+        format!("L{}", self.labels)
+    }
+
+    fn emit(&mut self, line: &str) {
+        self.out.push_str(line);
+        self.out.push('\n');
+    }
+}
@@ -0,0 +1,345 @@
+use std::collections::BTreeMap;
+
+use os::OsState;
+use program::Program;
+use vm_translator::vm_command::{ArithOp, Segment, VmCommand};
+
+pub const RAM_SIZE: usize = 32768;
+
+const SP: u16 = 0;
+const LCL: u16 = 1;
+const ARG: u16 = 2;
+const THIS: u16 = 3;
+const THAT: u16 = 4;
+const TEMP: u16 = 5;
+
+/// Runs VM commands directly on a Hack-sized RAM, with the same memory
+/// layout the translated program uses, so RAM can be compared between the
+/// two. The scratch registers R13-R15 are never touched.
+pub struct Vm {
+    pub program: Program,
+    pub ram: Vec<u16>,
+    /// Index of the next command
+    pub pc: usize,
+    pub steps: u64,
+    /// Called function and calling command for each active call, oldest
+    /// first
+    pub frames: Vec<(usize, Option<usize>)>,
+    pub os: OsState,
+    /// Set by the built-in `Sys.halt`
+    pub stopped: bool,
+    /// Steps after which calls made by built-in functions give up
+    pub step_limit: u64,
+    // The `call` of the built-in function running, if any
+    call_site: Option<usize>,
+    // Return address saved for resuming at each command, and back
+    addresses: Vec<u16>,
+    resume_points: BTreeMap<u16, usize>,
+}
+
+impl Vm {
+    pub fn new(program: Program) -> Self {
+        let addresses = (0..program.commands.len() + 1).map(|index| index as u16).collect();
+        let pc = program.start;
+        let mut vm = Vm {
+            program,
+            ram: vec![0; RAM_SIZE],
+            pc,
+            steps: 0,
+            frames: Vec::new(),
+            os: OsState::new(),
+            stopped: false,
+            step_limit: u64::MAX,
+            call_site: None,
+            addresses: Vec::new(),
+            resume_points: BTreeMap::new(),
+        };
+        vm.set_addresses(addresses);
+        vm
+    }
+
+    /// Saves return addresses as the ROM addresses each command starts at,
+    /// from a `vm_translator --annotate` ROM map, instead of command
+    /// indices. Frames on the stack then match the translated program's.
+    pub fn set_rom_map(&mut self, rom_map: &[(usize, String, usize)]) {
+        let starts: BTreeMap<(&str, usize), u16> = rom_map.iter()
+            .map(|&(address, ref vm_file, vm_line)| ((vm_file.as_str(), vm_line), address as u16))
+            .collect();
+        let mut addresses: Vec<u16> = self.program.locations
+            .iter()
+            .map(|&(ref vm_file, vm_line)| starts.get(&(vm_file.as_str(), vm_line)).cloned().unwrap_or(0))
+            .collect();
+        // After the last command comes the halting loop
+        let end = addresses.last().map_or(0, |&address| address + 1);
+        addresses.push(end);
+        self.set_addresses(addresses);
+    }
+
+    fn set_addresses(&mut self, addresses: Vec<u16>) {
+        // Labels share their address with the next command, so resume at
+        // the first of them
+        self.resume_points.clear();
+        for (index, &address) in addresses.iter().enumerate() {
+            self.resume_points.entry(address).or_insert(index);
+        }
+        self.addresses = addresses;
+    }
+
+    /// Sets SP to 256 and calls `Sys.init`, like the bootstrap code.
+    pub fn bootstrap(&mut self) -> Result<(), String> {
+        let init = *self.program.functions.get("Sys.init").ok_or("Sys.init is not defined")?;
+        self.write(SP, 256);
+        self.call(init, 0, None);
+        Ok(())
+    }
+
+    /// A program is done once it runs off its end, where the translator
+    /// puts its halting loop, spins on `label L; goto L` or calls the
+    /// built-in `Sys.halt`.
+    pub fn halted(&self) -> bool {
+        if self.stopped || self.pc >= self.program.commands.len() {
+            return true;
+        }
+        match self.program.commands[self.pc] {
+            VmCommand::Goto(_) => self.program.target(self.pc) == Some(self.pc.wrapping_sub(1)),
+            _ => false,
+        }
+    }
+
+    /// Executes one command, reporting broken calls and returns as
+    /// `file:line: message`.
+    pub fn step(&mut self) -> Result<(), String> {
+        let index = self.pc;
+        self.pc += 1;
+        self.steps += 1;
+
+        match self.program.commands[index] {
+            VmCommand::Arithmetic(op) => self.arithmetic(op),
+            VmCommand::Push(segment, i) => {
+                let value = match segment {
+                    Segment::Constant => i,
+                    _ => {
+                        let address = self.address(index, segment, i);
+                        self.read(address)
+                    }
+                };
+                self.push(value);
+            }
+            VmCommand::Pop(segment, i) => {
+                let address = self.address(index, segment, i);
+                let value = self.pop();
+                self.write(address, value);
+            }
+            VmCommand::Label(_) => (),
+            VmCommand::Goto(_) => self.jump(index)?,
+            VmCommand::IfGoto(_) => {
+                if self.pop() != 0 {
+                    self.jump(index)?;
+                }
+            }
+            VmCommand::Function(_, locals) => {
+                for _ in 0..locals {
+                    self.push(0);
+                }
+            }
+            VmCommand::Call(_, args) => self.call_command(index, args)?,
+            VmCommand::Return => {
+                let frame = self.read(LCL);
+                let address = self.read(frame.wrapping_sub(5));
+                let value = self.read(self.read(SP).wrapping_sub(1));
+                let arg = self.read(ARG);
+                self.write(arg, value);
+                self.write(SP, arg.wrapping_add(1));
+                for (offset, register) in (1..5).zip(&[THAT, THIS, ARG, LCL]) {
+                    let saved = self.read(frame.wrapping_sub(offset));
+                    self.write(*register, saved);
+                }
+
+                self.pc = *self.resume_points.get(&address).ok_or_else(|| {
+                    format!("{}: Return to address {}, where no command starts", self.program.location(index), address)
+                })?;
+                self.frames.pop();
+            }
+        }
+        Ok(())
+    }
+
+    /// Steps until the program halts or `max_steps` commands have run.
+    pub fn run(&mut self, max_steps: u64) -> Result<(), String> {
+        self.step_limit = max_steps;
+        while self.steps < max_steps && !self.halted() {
+            self.step()?;
+        }
+        Ok(())
+    }
+
+    /// Calls `name` with `args` from a built-in function and returns its
+    /// result, running the VM until a .vm function returns.
+    pub fn invoke(&mut self, name: &str, args: &[u16]) -> Result<u16, String> {
+        if self.stopped {
+            return Ok(0);
+        }
+        if let Some(builtin) = self.program.builtin_named(name) {
+            return (builtin.function)(self, args);
+        }
+        let function = *self.program.functions
+            .get(name)
+            .ok_or_else(|| format!("Function '{}' is not defined", name))?;
+
+        for &arg in args {
+            self.push(arg);
+        }
+        let (pc, depth) = (self.pc, self.frames.len());
+        let call_site = self.call_site;
+        self.call(function, args.len() as u16, call_site);
+        while self.frames.len() > depth {
+            if self.stopped {
+                return Ok(0);
+            }
+            if self.halted() || self.steps >= self.step_limit {
+                return Err(format!("{} did not return", name));
+            }
+            self.step()?;
+        }
+        self.pc = pc;
+        Ok(self.pop())
+    }
+
+    /// Active functions, innermost first, with the command that called each
+    pub fn backtrace(&self) -> Vec<String> {
+        self.frames
+            .iter()
+            .rev()
+            .map(|&(function, caller)| {
+                let name = self.program.function_at(function).unwrap_or("?");
+                match caller {
+                    Some(caller) => format!("{} called from {}", name, self.program.location(caller)),
+                    None => format!("{} called by the bootstrap code", name),
+                }
+            })
+            .collect()
+    }
+
+    pub fn read(&self, address: u16) -> u16 {
+        self.ram[(address & 0x7FFF) as usize]
+    }
+
+    pub fn write(&mut self, address: u16, value: u16) {
+        self.ram[(address & 0x7FFF) as usize] = value;
+    }
+
+    fn push(&mut self, value: u16) {
+        let sp = self.read(SP);
+        self.write(sp, value);
+        self.write(SP, sp.wrapping_add(1));
+    }
+
+    fn pop(&mut self) -> u16 {
+        let sp = self.read(SP).wrapping_sub(1);
+        self.write(SP, sp);
+        self.read(sp)
+    }
+
+    fn address(&self, index: usize, segment: Segment, i: u16) -> u16 {
+        match segment {
+            Segment::Argument => self.read(ARG).wrapping_add(i),
+            Segment::Local => self.read(LCL).wrapping_add(i),
+            Segment::This => self.read(THIS).wrapping_add(i),
+            Segment::That => self.read(THAT).wrapping_add(i),
+            Segment::Pointer => THIS + i,
+            Segment::Temp => TEMP + i,
+            Segment::Static => self.program.static_address(index),
+            Segment::Constant => unreachable!(),
+        }
+    }
+
+    fn arithmetic(&mut self, op: ArithOp) {
+        let truth = |condition: bool| if condition { 0xFFFF } else { 0 };
+
+        let y = self.pop();
+        if op.arity() == 1 {
+            let value = match op {
+                ArithOp::Neg => y.wrapping_neg(),
+                _ => !y,
+            };
+            self.push(value);
+            return;
+        }
+
+        let x = self.pop();
+        let value = match op {
+            ArithOp::Add => x.wrapping_add(y),
+            ArithOp::Sub => x.wrapping_sub(y),
+            ArithOp::Eq => truth(x == y),
+            ArithOp::Gt => truth(x as i16 > y as i16),
+            ArithOp::Lt => truth((x as i16) < y as i16),
+            ArithOp::And => x & y,
+            ArithOp::Or => x | y,
+            ArithOp::Mul => x.wrapping_mul(y),
+            ArithOp::Div if y == 0 => 0,
+            ArithOp::Div => (x as i16).wrapping_div(y as i16) as u16,
+            ArithOp::Shl | ArithOp::Shr if y >= 16 => 0,
+            ArithOp::Shl => x << y,
+            ArithOp::Shr => x >> y,
+            _ => unreachable!(),
+        };
+        self.push(value);
+    }
+
+    fn call_command(&mut self, index: usize, args: u16) -> Result<(), String> {
+        if let Some(builtin) = self.program.builtin(index) {
+            if args != builtin.args {
+                return Err(format!("{}: {} takes {} arguments, not {}",
+                                   self.program.location(index), builtin.name, builtin.args, args));
+            }
+            let sp = self.read(SP).wrapping_sub(args);
+            let arguments: Vec<u16> = (0..args).map(|i| self.read(sp.wrapping_add(i))).collect();
+            self.write(SP, sp);
+
+            let call_site = self.call_site.replace(index);
+            let result = (builtin.function)(self, &arguments);
+            self.call_site = call_site;
+            let value = result.map_err(|message| format!("{}: {}", self.program.location(index), message))?;
+            self.push(value);
+            return Ok(());
+        }
+
+        match self.program.target(index) {
+            Some(function) => {
+                self.call(function, args, Some(index));
+                Ok(())
+            }
+            None => Err(format!("{}: '{}' calls an undefined function",
+                                self.program.location(index),
+                                self.program.commands[index])),
+        }
+    }
+
+    fn jump(&mut self, index: usize) -> Result<(), String> {
+        match self.program.target(index) {
+            Some(target) => {
+                self.pc = target;
+                Ok(())
+            }
+            None => Err(format!("{}: The label of '{}' is not defined",
+                                self.program.location(index),
+                                self.program.commands[index])),
+        }
+    }
+
+    fn call(&mut self, function: usize, args: u16, caller: Option<usize>) {
+        let resume = caller.map_or(self.program.start, |caller| caller + 1);
+        let address = self.addresses[resume];
+        self.push(address);
+        for &register in &[LCL, ARG, THIS, THAT] {
+            let saved = self.read(register);
+            self.push(saved);
+        }
+        let sp = self.read(SP);
+        self.write(ARG, sp.wrapping_sub(args + 5));
+        self.write(LCL, sp);
+
+        self.pc = function;
+        self.frames.push((function, caller));
+    }
+}
@@ -0,0 +1,69 @@
+use std::env;
+use std::fs;
+use std::fs::File;
+use std::io;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::process;
+
+extern crate getopts;
+use getopts::Options;
+use getopts::ParsingStyle;
+
+extern crate vm_translator;
+use vm_translator::{parse_sources, read_sources};
+use vm_translator::optimizer::optimize;
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let program = args[0].clone();
+
+    let mut opts = Options::new();
+    opts.optopt("o", "", "write to this file, or directory for a directory input", "OUTPATH");
+    opts.parsing_style(ParsingStyle::FloatingFrees);
+
+    let matches = opts.parse(&args[1..]).expect("Unable to parse arguments.");
+
+    let in_path = if matches.free.len() == 1 {
+        Path::new(&matches.free[0])
+    } else {
+        let brief = format!("Usage: {} FILE [options]", program);
+        println!("{}", opts.usage(&brief));
+        return;
+    };
+
+    let sources = read_sources(in_path).expect("Unable to read file.");
+    let parsed = match parse_sources(&sources) {
+        Ok(parsed) => parsed,
+        Err(errors) => {
+            for error in &errors {
+                eprintln!("{}", error);
+            }
+            process::exit(1);
+        }
+    };
+
+    // A single file goes to stdout unless -o is given
+    let out_path = matches.opt_str("o");
+    if in_path.is_dir() {
+        let out_dir = out_path.as_ref().expect("An output directory is needed for a directory.");
+        fs::create_dir_all(out_dir).expect("Unable to create directory.");
+    }
+
+    for (source, commands) in sources.iter().zip(parsed) {
+        let out: Box<dyn Write> = match (in_path.is_dir(), out_path.as_ref()) {
+            (true, Some(out_dir)) => {
+                let file_name = Path::new(&source.name).file_name().unwrap();
+                Box::new(File::create(Path::new(out_dir).join(file_name)).expect("Unable to create file."))
+            }
+            (_, Some(out_file)) => Box::new(File::create(out_file).expect("Unable to create file.")),
+            (_, None) => Box::new(io::stdout()),
+        };
+
+        let mut out = BufWriter::new(out);
+        for (_, command) in optimize(&commands) {
+            writeln!(out, "{}", command).expect("Unable to write to file.");
+        }
+        out.flush().expect("Unable to write to file.");
+    }
+}
@@ -0,0 +1,416 @@
+use std::collections::BTreeMap;
+use std::io;
+use std::io::prelude::*;
+use std::mem;
+
+use vm_command::{ArithOp, Segment, VmCommand};
+
+// First RAM address the assembler gives a variable
+const STATIC_BASE: u16 = 16;
+
+/// C code every natively run program needs, whatever it was translated
+/// to: a Hack-like `ram` array of 64K words, so that no 16-bit address can
+/// fall outside it, and the `main` that handles the command line and runs
+/// `vm_start`. The program ends by calling `vm_halt`, which prints RAM and
+/// writes the screen as asked, or `vm_fail` with a message.
+pub const RUNTIME: &str = r#"#include <stdint.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+typedef uint16_t word;
+
+word ram[65536];
+
+static const char *screen_file;
+static const char **ranges;
+static int num_ranges;
+
+void vm_start(void);
+
+/* Two's complement value of a word */
+static long sign(word value)
+{
+    return (long)value - ((long)(value & 0x8000) << 1);
+}
+
+/* Writes the screen as a plain PBM image, 1 for black */
+static void dump_screen(const char *path)
+{
+    FILE *file = fopen(path, "w");
+    int row, column;
+
+    if (!file) {
+        perror(path);
+        exit(1);
+    }
+    fprintf(file, "P1\n512 256\n");
+    for (row = 0; row < 256; row++) {
+        for (column = 0; column < 512; column++) {
+            word pixels = ram[16384 + row * 32 + column / 16];
+            fputc((pixels >> (column % 16)) & 1 ? '1' : '0', file);
+            fputc(column % 64 == 63 ? '\n' : ' ', file);
+        }
+    }
+    fclose(file);
+}
+
+/* Prints the requested RAM and exits */
+void vm_halt(void)
+{
+    int i;
+    long lo, hi, address;
+
+    for (i = 0; i < num_ranges; i++) {
+        char *end;
+        lo = strtol(ranges[i], &end, 10);
+        hi = *end == '-' ? strtol(end + 1, &end, 10) : lo;
+        if (*end || lo < 0 || hi < lo || hi > 65535) {
+            fprintf(stderr, "Invalid range: %s\n", ranges[i]);
+            exit(1);
+        }
+        for (address = lo; address <= hi; address++) {
+            printf("RAM[%ld] = %ld\n", address, sign(ram[address]));
+        }
+    }
+    if (screen_file) {
+        dump_screen(screen_file);
+    }
+    exit(0);
+}
+
+void vm_fail(const char *message)
+{
+    fprintf(stderr, "Error: %s\n", message);
+    exit(1);
+}
+
+int main(int argc, char **argv)
+{
+    int i;
+    long address, value;
+    char *end;
+
+    ram[0] = 256;
+    ranges = malloc(argc * sizeof *ranges);
+    for (i = 1; i < argc; i++) {
+        if ((!strcmp(argv[i], "-p") || !strcmp(argv[i], "--print")) && i + 1 < argc) {
+            ranges[num_ranges++] = argv[++i];
+        } else if (!strcmp(argv[i], "--screen") && i + 1 < argc) {
+            screen_file = argv[++i];
+        } else if (!strcmp(argv[i], "--set") && i + 1 < argc) {
+            address = strtol(argv[++i], &end, 10);
+            value = *end == '=' ? strtol(end + 1, &end, 10) : 65536;
+            if (*end || address < 0 || address > 65535 || value < -32768 || value > 65535) {
+                fprintf(stderr, "Invalid setting: %s\n", argv[i]);
+                return 1;
+            }
+            ram[address] = (word)value;
+        } else {
+            fprintf(stderr, "Usage: %s [--set ADDR=VALUE]... [-p LO-HI]... [--screen FILE.pbm]\n", argv[0]);
+            return 1;
+        }
+    }
+
+    vm_start();
+    return 0;
+}
+"#;
+
+// What the translated functions use on top of the runtime
+const PRELUDE: &str = r#"
+static word y;
+
+#define SP ram[0]
+#define LCL ram[1]
+#define ARG ram[2]
+#define THIS ram[3]
+#define THAT ram[4]
+#define TOP ram[(word)(SP - 1)]
+#define AT(base, index) ram[(word)((base) + (index))]
+#define TRUTH(condition) ((condition) ? 0xFFFF : 0)
+
+static void push(word value)
+{
+    ram[SP++] = value;
+}
+
+static word pop(void)
+{
+    return ram[--SP];
+}
+
+/* Saves the caller's frame as the Hack call sequence does and runs
+   function */
+static void call(void (*function)(void), word num_args, word return_address)
+{
+    push(return_address);
+    push(LCL);
+    push(ARG);
+    push(THIS);
+    push(THAT);
+    ARG = SP - num_args - 5;
+    LCL = SP;
+    function();
+}
+
+/* Moves the return value to the caller's stack and restores its frame */
+static void ret(void)
+{
+    word frame = LCL;
+
+    AT(ARG, 0) = pop();
+    SP = ARG + 1;
+    THAT = AT(frame, -1);
+    THIS = AT(frame, -2);
+    ARG = AT(frame, -3);
+    LCL = AT(frame, -4);
+}
+
+static void locals(word count)
+{
+    while (count--) {
+        push(0);
+    }
+}
+
+"#;
+
+/// Writes a whole VM program as one C file that runs it natively. The C
+/// program keeps all of its data in a Hack-like `ram` array, with the
+/// stack, frames, segments and statics where the Hack translation puts
+/// them, so its memory can be compared with the emulators'. Each VM
+/// function becomes a C function and labels become C labels.
+///
+/// The program halts when it enters `Sys.halt`, reaches a `goto` to the
+/// label just before it, returns from `Sys.init` or runs off the end of a
+/// function. It then prints the RAM ranges given with `-p LO-HI` in the
+/// same form as `vm_emulator` and writes the screen to the PBM file given
+/// with `--screen`. RAM can be set beforehand with `--set ADDR=VALUE`. The
+/// keyboard always reads 0, and division by zero gives 0 as in the Hack
+/// translation.
+pub struct CWriter<W: Write> {
+    out_file: W,
+    file_name: String,
+    // Index of every function defined in the program, which names its C
+    // function
+    functions: BTreeMap<String, usize>,
+    // Whether the C function being written is the first one, holding the
+    // commands before any `function`
+    at_start: bool,
+    bootstrap: bool,
+    // Number of each label of the current function, and whether it has
+    // been defined
+    labels: BTreeMap<String, (usize, bool)>,
+    last_label: Option<String>,
+    statics: BTreeMap<String, u16>,
+    // Commands written so far. A call saves the index of the command
+    // after it as its return address, as `vm_emulator` does.
+    commands: u16,
+}
+
+impl<W: Write> CWriter<W> {
+    pub fn new(out_file: W) -> Self {
+        CWriter {
+            out_file,
+            file_name: "".to_string(),
+            functions: BTreeMap::new(),
+            at_start: true,
+            bootstrap: false,
+            labels: BTreeMap::new(),
+            last_label: None,
+            statics: BTreeMap::new(),
+            commands: 0,
+        }
+    }
+
+    /// Gives back the underlying writer
+    pub fn into_inner(self) -> W {
+        self.out_file
+    }
+
+    /// Writes the runtime and a declaration of every function, which must
+    /// be all those the program defines. Starts the code run before any
+    /// function: with `bootstrap` that is a call to `Sys.init`, otherwise
+    /// it is the commands that come before the first `function`.
+    pub fn write_init(&mut self, functions: &[String], bootstrap: bool) -> io::Result<()> {
+        writeln!(self.out_file, "/* Translated from VM code by vm_translator */")?;
+        self.out_file.write_all(RUNTIME.as_bytes())?;
+        self.out_file.write_all(PRELUDE.as_bytes())?;
+        for (index, function) in functions.iter().enumerate() {
+            self.functions.insert(function.clone(), index);
+            writeln!(self.out_file, "static void f{}(void); /* {} */", index, function)?;
+        }
+
+        writeln!(self.out_file, "\nvoid vm_start(void)\n{{")?;
+        self.bootstrap = bootstrap;
+        if bootstrap {
+            self.write_call("Sys.init", 0)?;
+            writeln!(self.out_file, "    vm_halt();")?;
+        }
+        Ok(())
+    }
+
+    /// Writes `text` as a comment line of its own
+    pub fn write_comment(&mut self, text: &str) -> io::Result<()> {
+        writeln!(self.out_file, "    /* {} */", text.replace("*/", "* /"))
+    }
+
+    pub fn set_file_name(&mut self, name: &str) {
+        self.file_name = name.to_string();
+    }
+
+    pub fn write_command(&mut self, command: &VmCommand) -> io::Result<()> {
+        let last_label = self.last_label.take();
+        self.commands = self.commands.wrapping_add(1);
+        match *command {
+            VmCommand::Arithmetic(op) => self.write_arithmetic(op),
+            VmCommand::Push(segment, index) => self.write_push(segment, index),
+            VmCommand::Pop(segment, index) => self.write_pop(segment, index),
+            VmCommand::Label(ref label) => {
+                self.last_label = Some(label.clone());
+                self.write_label(label)
+            }
+            // A jump to the label just before it is how programs halt
+            VmCommand::Goto(ref label) if last_label.as_ref() == Some(label) => {
+                writeln!(self.out_file, "    vm_halt();")
+            }
+            VmCommand::Goto(ref label) => self.write_goto(label),
+            VmCommand::IfGoto(ref label) => self.write_if(label),
+            VmCommand::Function(ref name, num_locals) => self.write_function(name, num_locals),
+            VmCommand::Return => self.write_return(),
+            VmCommand::Call(ref name, num_args) => self.write_call(name, num_args),
+        }
+    }
+
+    pub fn write_arithmetic(&mut self, op: ArithOp) -> io::Result<()> {
+        let value = match op {
+            ArithOp::Neg => return writeln!(self.out_file, "    TOP = -TOP;"),
+            ArithOp::Not => return writeln!(self.out_file, "    TOP = ~TOP;"),
+            ArithOp::Add => "TOP + y",
+            ArithOp::Sub => "TOP - y",
+            ArithOp::Eq => "TRUTH(TOP == y)",
+            ArithOp::Gt => "TRUTH(sign(TOP) > sign(y))",
+            ArithOp::Lt => "TRUTH(sign(TOP) < sign(y))",
+            ArithOp::And => "TOP & y",
+            ArithOp::Or => "TOP | y",
+            ArithOp::Mul => "(uint32_t)TOP * y",
+            ArithOp::Div => "y == 0 ? 0 : sign(TOP) / sign(y)",
+            ArithOp::Shl => "y >= 16 ? 0 : (uint32_t)TOP << y",
+            ArithOp::Shr => "y >= 16 ? 0 : TOP >> y",
+        };
+        writeln!(self.out_file, "    y = pop();\n    TOP = (word)({});", value)
+    }
+
+    pub fn write_push(&mut self, segment: Segment, index: u16) -> io::Result<()> {
+        match segment {
+            Segment::Constant => writeln!(self.out_file, "    push({});", index),
+            _ => {
+                let address = self.address(segment, index);
+                writeln!(self.out_file, "    push({});", address)
+            }
+        }
+    }
+
+    pub fn write_pop(&mut self, segment: Segment, index: u16) -> io::Result<()> {
+        let address = self.address(segment, index);
+        writeln!(self.out_file, "    y = pop();\n    {} = y;", address)
+    }
+
+    // C expression for the word `segment index` refers to
+    fn address(&mut self, segment: Segment, index: u16) -> String {
+        match segment {
+            Segment::Local => format!("AT(LCL, {})", index),
+            Segment::Argument => format!("AT(ARG, {})", index),
+            Segment::This => format!("AT(THIS, {})", index),
+            Segment::That => format!("AT(THAT, {})", index),
+            Segment::Pointer => format!("ram[{}]", 3 + index),
+            Segment::Temp => format!("ram[{}]", 5 + index),
+            // The assembler numbers variables in order of first use
+            Segment::Static => {
+                let next = STATIC_BASE + self.statics.len() as u16;
+                let name = format!("{}.{}", self.file_name, index);
+                format!("ram[{}]", *self.statics.entry(name).or_insert(next))
+            }
+            Segment::Constant => unreachable!(),
+        }
+    }
+
+    // Number naming the C label of `label` in the current function
+    fn label_number(&mut self, label: &str) -> usize {
+        let next = self.labels.len();
+        self.labels.entry(label.to_string()).or_insert((next, false)).0
+    }
+
+    pub fn write_label(&mut self, label: &str) -> io::Result<()> {
+        let number = self.label_number(label);
+        self.labels.get_mut(label).unwrap().1 = true;
+        writeln!(self.out_file, "L{}:;", number)
+    }
+
+    pub fn write_goto(&mut self, label: &str) -> io::Result<()> {
+        let number = self.label_number(label);
+        writeln!(self.out_file, "    goto L{};", number)
+    }
+
+    pub fn write_if(&mut self, label: &str) -> io::Result<()> {
+        let number = self.label_number(label);
+        writeln!(self.out_file, "    if (pop()) goto L{};", number)
+    }
+
+    pub fn write_function(&mut self, function_name: &str, num_locals: u16) -> io::Result<()> {
+        let index = self.functions[function_name];
+        // Without the bootstrap code the commands before the first
+        // function run on into it
+        if self.at_start && !self.bootstrap {
+            writeln!(self.out_file, "    f{}();", index)?;
+        }
+        self.end_function()?;
+        self.at_start = false;
+
+        writeln!(self.out_file, "\nstatic void f{}(void) /* {} */\n{{", index, function_name)?;
+        if function_name == "Sys.halt" {
+            writeln!(self.out_file, "    vm_halt();")?;
+        }
+        if num_locals > 0 {
+            writeln!(self.out_file, "    locals({});", num_locals)?;
+        }
+        Ok(())
+    }
+
+    // Ends the C function being written. Jumps to labels it never defined
+    // fail at run time.
+    fn end_function(&mut self) -> io::Result<()> {
+        writeln!(self.out_file, "    vm_halt();")?;
+        let labels = mem::take(&mut self.labels);
+        for (label, (number, defined)) in labels {
+            if !defined {
+                writeln!(self.out_file, "L{}:\n    vm_fail(\"Label {} is not defined\");", number, label)?;
+            }
+        }
+        writeln!(self.out_file, "}}")
+    }
+
+    pub fn write_return(&mut self) -> io::Result<()> {
+        if self.at_start {
+            return writeln!(self.out_file, "    vm_fail(\"return outside any function\");");
+        }
+        writeln!(self.out_file, "    ret();\n    return;")
+    }
+
+    pub fn write_call(&mut self, function_name: &str, num_args: u16) -> io::Result<()> {
+        match self.functions.get(function_name) {
+            Some(index) => writeln!(self.out_file, "    call(f{}, {}, {});", index, num_args, self.commands),
+            None => {
+                writeln!(self.out_file,
+                         "    vm_fail(\"Call to undefined function {}\");",
+                         function_name)
+            }
+        }
+    }
+
+    /// Ends the last function
+    pub fn close(&mut self) -> io::Result<()> {
+        self.end_function()
+    }
+}
@@ -0,0 +1,68 @@
+//! Whole-program reachability over `call` commands, used to drop functions
+//! a program never calls, such as most of the OS in small programs.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use vm_command::VmCommand;
+
+/// Functions reachable from `root` by following calls, including `root`
+/// itself. Calls to functions no file defines are followed no further.
+pub fn reachable(files: &[Vec<(usize, VmCommand)>], root: &str) -> BTreeSet<String> {
+    let mut calls: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for commands in files {
+        let mut current = None;
+        for (_, command) in commands {
+            match *command {
+                VmCommand::Function(ref name, _) => {
+                    current = Some(name.as_str());
+                    calls.entry(name).or_default();
+                }
+                VmCommand::Call(ref name, _) => {
+                    if let Some(caller) = current {
+                        calls.entry(caller).or_default().push(name);
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+
+    let mut reached = BTreeSet::new();
+    let mut pending = vec![root];
+    while let Some(function) = pending.pop() {
+        if reached.insert(function.to_string()) {
+            if let Some(callees) = calls.get(function) {
+                pending.extend(callees.iter().cloned());
+            }
+        }
+    }
+    reached
+}
+
+/// Removes every function not reachable from `root` and returns the names
+/// of those removed. Nothing is removed when `root` is not defined, as in
+/// single-file tests without `Sys.init`.
+pub fn remove_unreachable(files: &mut [Vec<(usize, VmCommand)>], root: &str) -> Vec<String> {
+    let defined = files.iter()
+        .flat_map(|commands| commands.iter())
+        .any(|(_, command)| matches!(*command, VmCommand::Function(ref name, _) if name == root));
+    if !defined {
+        return Vec::new();
+    }
+
+    let reached = reachable(files, root);
+    let mut removed = Vec::new();
+    for commands in files.iter_mut() {
+        let mut keep = true;
+        commands.retain(|(_, command)| {
+            if let VmCommand::Function(ref name, _) = *command {
+                keep = reached.contains(name);
+                if !keep {
+                    removed.push(name.clone());
+                }
+            }
+            keep
+        });
+    }
+    removed
+}
@@ -0,0 +1,1244 @@
+use std::io;
+use std::io::prelude::*;
+
+use guards::Guard;
+use vm_command::{ArithOp, Segment, VmCommand};
+
+// First address past the stack, where the heap starts
+const STACK_END: u16 = 2048;
+
+// Passes writes through while counting lines, so commands can be located
+// in the output for source maps
+struct LineCounter<W> {
+    inner: W,
+    lines: usize,
+}
+
+impl<W: Write> Write for LineCounter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.lines += buf[..written].iter().filter(|&&b| b == b'\n').count();
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Writes Hack assembly for VM commands to any `Write`. Output is not
+/// buffered here, so wrap files in a `BufWriter`; a `Vec<u8>` keeps the
+/// assembly in memory.
+pub struct CodeWriter<W: Write> {
+    out_file: LineCounter<W>,
+    file_name: String,
+    function_name: String,
+    counter: u16,
+    compact: bool,
+    // Shared routines jumped to by compact code, written by `write_runtime`
+    routines: Vec<String>,
+    cache_top: bool,
+    // The top of the stack is in D rather than at RAM[SP-1]
+    cached: bool,
+    // Functions written so far, the last being the current one
+    functions: Vec<String>,
+    num_locals: u16,
+}
+
+impl<W: Write> CodeWriter<W> {
+    pub fn new(out_file: W) -> Self {
+        CodeWriter {
+            out_file: LineCounter {
+                inner: out_file,
+                lines: 0,
+            },
+            file_name: "".to_string(),
+            function_name: "".to_string(),
+            counter: 0,
+            compact: false,
+            routines: Vec::new(),
+            cache_top: false,
+            cached: false,
+            functions: Vec::new(),
+            num_locals: 0,
+        }
+    }
+
+    /// In compact mode calls, returns and comparisons jump to a shared
+    /// routine instead of being written out in full at every site, and
+    /// stack accesses use the shortest sequence for their index. The
+    /// routines follow the program when `write_runtime` is called.
+    pub fn set_compact(&mut self, compact: bool) {
+        self.compact = compact;
+    }
+
+    /// With the top of the stack cached, a pushed value stays in D until
+    /// the next command needs the stack in memory. The cache is written
+    /// back before labels, jumps, calls, returns and function entries, so
+    /// every basic block starts and ends with the plain stack layout.
+    pub fn set_cache_top(&mut self, cache_top: bool) {
+        self.cache_top = cache_top;
+    }
+
+    /// Functions written so far, in order, so function `i` has ID `i + 1`
+    pub fn functions(&self) -> &[String] {
+        &self.functions
+    }
+
+    // Writes a cached top of the stack back to memory
+    fn flush(&mut self) -> io::Result<()> {
+        if !self.cached {
+            return Ok(());
+        }
+        self.cached = false;
+        self.out_file.write_all(b"@SP \n\
+                                  AM=M+1 \n\
+                                  A=A-1 \n\
+                                  M=D \n")
+    }
+
+    // Pops the top of the stack into D unless it is there already
+    fn load_top(&mut self) -> io::Result<()> {
+        if self.cached {
+            return Ok(());
+        }
+        self.cached = true;
+        self.out_file.write_all(b"@SP \n\
+                                  AM=M-1 \n\
+                                  D=M \n")
+    }
+
+    /// Gives back the underlying writer, e.g. to take the assembly out of
+    /// an in-memory buffer.
+    pub fn into_inner(self) -> W {
+        self.out_file.inner
+    }
+
+    /// 1-based line of the .asm file the next command starts at
+    pub fn line_number(&self) -> usize {
+        self.out_file.lines + 1
+    }
+
+    /// Writes `text` as a comment line of its own
+    pub fn write_comment(&mut self, text: &str) -> io::Result<()> {
+        writeln!(self.out_file, "// {}", text)
+    }
+
+    pub fn set_file_name(&mut self, name: &str) {
+        self.file_name = name.to_string();
+    }
+
+    pub fn write_init(&mut self) -> io::Result<()> {
+        self.out_file.write_all(b"@256 \n\
+                                  D=A \n\
+                                  @SP \n\
+                                  M=D \n")?;
+
+        self.write_call("Sys.init", 0)
+    }
+
+    pub fn write_command(&mut self, command: &VmCommand) -> io::Result<()> {
+        match *command {
+            VmCommand::Arithmetic(op) => self.write_arithmetic(op),
+            VmCommand::Push(segment, index) => self.write_push(segment, index),
+            VmCommand::Pop(segment, index) => self.write_pop(segment, index),
+            VmCommand::Label(ref label) => self.write_label(label),
+            VmCommand::Goto(ref label) => self.write_goto(label),
+            VmCommand::IfGoto(ref label) => self.write_if(label),
+            VmCommand::Function(ref name, num_locals) => self.write_function(name, num_locals),
+            VmCommand::Call(ref name, num_args) => self.write_call(name, num_args),
+            VmCommand::Return => self.write_return(),
+        }
+    }
+
+    /// Writes `command` after the checks `guards` makes, which for a
+    /// function entry go between its label and its locals.
+    pub fn write_guarded(&mut self, command: &VmCommand, guards: &[Guard]) -> io::Result<()> {
+        if let VmCommand::Function(ref name, num_locals) = *command {
+            self.write_function_label(name, num_locals)?;
+            for &guard in guards {
+                self.write_guard(guard)?;
+            }
+            return self.write_locals(num_locals);
+        }
+
+        for &guard in guards {
+            self.write_guard(guard)?;
+        }
+        self.write_command(command)
+    }
+
+    /// Writes a check that, failing, leaves the guard's error code in R13
+    /// and the ID of the current function in R14 and halts. Functions are
+    /// numbered from 1 in the order written, and code outside any is 0.
+    pub fn write_guard(&mut self, guard: Guard) -> io::Result<()> {
+        self.flush()?;
+
+        let jump = match guard {
+            Guard::Overflow(count) => {
+                write!(self.out_file, "@SP \nD=M \n@{} \nD=D-A \n", STACK_END.saturating_sub(count))?;
+                "JGT"
+            }
+            Guard::Underflow(count) if self.functions.is_empty() => {
+                write!(self.out_file, "@SP \nD=M \n@{} \nD=D-A \n", 256 + count)?;
+                "JLT"
+            }
+            Guard::Underflow(count) => {
+                write!(self.out_file,
+                       "@LCL \nD=M \n@SP \nD=M-D \n@{} \nD=D-A \n",
+                       self.num_locals.saturating_add(count))?;
+                "JLT"
+            }
+            Guard::Null(segment) => {
+                write!(self.out_file, "@{} \nD=M \n", base_register(segment).unwrap())?;
+                "JEQ"
+            }
+            Guard::DivideByZero => {
+                self.out_file.write_all(b"@SP \nA=M-1 \nD=M \n")?;
+                "JEQ"
+            }
+        };
+
+        let routine = format!("FAIL{}_{}", guard.error_code(), self.functions.len());
+        write!(self.out_file, "@__{} \nD;{} \n", routine, jump)?;
+        self.use_routine(routine);
+        self.use_routine("FAILED".to_string());
+        Ok(())
+    }
+
+    pub fn write_arithmetic(&mut self, op: ArithOp) -> io::Result<()> {
+        // Loops too long to write out at every use
+        if let ArithOp::Mul | ArithOp::Div | ArithOp::Shl | ArithOp::Shr = op {
+            self.flush()?;
+            let routine = op.to_string().to_uppercase();
+            return self.write_routine_jump(routine.clone(), &routine);
+        }
+
+        if self.cache_top {
+            return self.write_cached_arithmetic(op);
+        }
+
+        match op {
+            ArithOp::Add => self.write_binary_op("M=D+M"),
+            ArithOp::Sub => self.write_binary_op("M=M-D"),
+            ArithOp::Neg => self.write_unary_op("M=-M"),
+            ArithOp::Eq if self.compact => self.write_routine_jump("EQ".to_string(), "COMPARE"),
+            ArithOp::Gt if self.compact => self.write_routine_jump("GT".to_string(), "COMPARE"),
+            ArithOp::Lt if self.compact => self.write_routine_jump("LT".to_string(), "COMPARE"),
+            ArithOp::Eq => self.write_binary_jmp("JEQ"),
+            ArithOp::Gt | ArithOp::Lt => {
+                self.out_file.write_all(b"@SP \nAM=M-1 \nD=M \n")?;
+                let prefix = format!("COMPARE{}", self.counter);
+                self.counter += 1;
+                self.write_signed_compare(op, &prefix)?;
+                self.out_file.write_all(b"@SP \nA=M-1 \nM=D \n")
+            }
+            ArithOp::And => self.write_binary_op("M=D&M"),
+            ArithOp::Or => self.write_binary_op("M=D|M"),
+            ArithOp::Not => self.write_unary_op("M=!M"),
+            ArithOp::Mul | ArithOp::Div | ArithOp::Shl | ArithOp::Shr => unreachable!(),
+        }
+    }
+
+    // Leaves the result in D, taking the second operand from RAM[SP-1]
+    fn write_cached_arithmetic(&mut self, op: ArithOp) -> io::Result<()> {
+        self.load_top()?;
+
+        let operation = match op {
+            ArithOp::Neg => return self.out_file.write_all(b"D=-D \n"),
+            ArithOp::Not => return self.out_file.write_all(b"D=!D \n"),
+            ArithOp::Add => "D=D+M",
+            ArithOp::Sub => "D=M-D",
+            ArithOp::And => "D=D&M",
+            ArithOp::Or => "D=D|M",
+            ArithOp::Eq => "D=M-D",
+            ArithOp::Mul | ArithOp::Div | ArithOp::Shl | ArithOp::Shr => unreachable!(),
+            ArithOp::Gt | ArithOp::Lt => {
+                let prefix = format!("COMPARE{}", self.counter);
+                self.counter += 1;
+                self.write_signed_compare(op, &prefix)?;
+                return self.out_file.write_all(b"@SP \nM=M-1 \n");
+            }
+        };
+        writeln!(self.out_file, "@SP \nAM=M-1 \n{}", operation)?;
+
+        if op != ArithOp::Eq {
+            return Ok(());
+        }
+        write!(self.out_file,
+               "@TRUE{0} \nD;JEQ \nD=0 \n@CONTINUE{0} \n0;JMP \n(TRUE{0}) \nD=-1 \n(CONTINUE{0})\n",
+               self.counter)?;
+        self.counter += 1;
+        Ok(())
+    }
+
+    // Compares x at RAM[SP-1] with y in D for `gt` or `lt`, leaving true or
+    // false in D and x in place. x - y overflows when the signs differ, so
+    // those cases are settled by the signs alone.
+    fn write_signed_compare(&mut self, op: ArithOp, prefix: &str) -> io::Result<()> {
+        // Results when only x or only y is negative
+        let (x_negative, y_negative, jump) = match op {
+            ArithOp::Gt => (0, -1, "JGT"),
+            _ => (-1, 0, "JLT"),
+        };
+        write!(self.out_file,
+               "@R13 \n\
+                M=D \n\
+                @{0}_Y_NEGATIVE \n\
+                D;JLT \n\
+                @SP \n\
+                A=M-1 \n\
+                D=M \n\
+                @{0}_SAME_SIGN \n\
+                D;JGE \n\
+                D={1} \n\
+                @{0}_END \n\
+                0;JMP \n\
+                ({0}_Y_NEGATIVE) \n\
+                @SP \n\
+                A=M-1 \n\
+                D=M \n\
+                @{0}_SAME_SIGN \n\
+                D;JLT \n\
+                D={2} \n\
+                @{0}_END \n\
+                0;JMP \n\
+                ({0}_SAME_SIGN) \n\
+                @R13 \n\
+                D=D-M \n\
+                @{0}_TRUE \n\
+                D;{3} \n\
+                D=0 \n\
+                @{0}_END \n\
+                0;JMP \n\
+                ({0}_TRUE) \n\
+                D=-1 \n\
+                ({0}_END) \n",
+               prefix,
+               x_negative,
+               y_negative,
+               jump)
+    }
+
+    fn write_unary_op(&mut self, operation: &str) -> io::Result<()> {
+        write!(self.out_file, "@SP \nA=M-1 \n{}\n", operation)
+    }
+
+    fn write_binary_op(&mut self, operation: &str) -> io::Result<()> {
+        write!(self.out_file,
+               "@SP \nAM=M-1 \nD=M \nA=A-1 \n{}\n",
+               operation)
+    }
+
+    fn write_binary_jmp(&mut self, jump: &str) -> io::Result<()> {
+        write!(self.out_file,
+               "@SP \nAM=M-1 \nD=M \nA=A-1 \nD=M-D \n@FALSE{0} \nD;{1} \n@SP \nA=M-1 \nM=0 \
+                \n@CONTINUE{0} \n0;JMP \n(FALSE{0}) \n@SP \nA=M-1 \nM=-1 \n(CONTINUE{0})\n",
+               self.counter,
+               jump)?;
+        self.counter += 1;
+        Ok(())
+    }
+
+    pub fn write_push(&mut self, segment: Segment, index: u16) -> io::Result<()> {
+        if self.cache_top {
+            self.flush()?;
+            self.cached = true;
+            if segment == Segment::Constant && index <= 1 {
+                return writeln!(self.out_file, "D={}", index);
+            }
+        } else if self.compact && segment == Segment::Constant && index <= 1 {
+            return writeln!(self.out_file, "@SP \nAM=M+1 \nA=A-1 \nM={}", index);
+        }
+
+        self.write_load(segment, index)?;
+        if self.cache_top {
+            return Ok(());
+        }
+        self.write_push_and_increment()
+    }
+
+    /// Copies one segment entry to another without going through the
+    /// stack, for `push X; pop Y`.
+    pub fn write_move(&mut self, source: (Segment, u16), target: (Segment, u16)) -> io::Result<()> {
+        self.flush()?;
+
+        let (segment, index) = target;
+        match (base_register(segment), index <= 5) {
+            (Some(register), true) => {
+                self.write_load(source.0, source.1)?;
+                write!(self.out_file, "@{} \nA=M \n", register)?;
+                for _ in 0..index {
+                    self.out_file.write_all(b"A=A+1 \n")?;
+                }
+                self.out_file.write_all(b"M=D \n")
+            }
+            (Some(register), false) => {
+                self.write_temp_offset(register, index)?;
+                self.write_load(source.0, source.1)?;
+                self.out_file.write_all(b"@R13 \n\
+                                          A=M \n\
+                                          M=D \n")
+            }
+            (None, _) => {
+                self.write_load(source.0, source.1)?;
+                let location = match segment {
+                    Segment::Static => format!("{}.{}", self.file_name, index),
+                    Segment::Pointer => format!("{}", 3 + index),
+                    Segment::Temp => format!("{}", 5 + index),
+                    _ => panic!("Cannot pop to segment: {}", segment),
+                };
+                self.write_copy_to_temp(location)
+            }
+        }
+    }
+
+    // Loads a segment entry into D
+    fn write_load(&mut self, segment: Segment, index: u16) -> io::Result<()> {
+        match segment {
+            Segment::Argument => self.write_load_segment("ARG", index),
+            Segment::Local => self.write_load_segment("LCL", index),
+            Segment::Static => {
+                let name = self.file_name.clone();
+                self.write_load_literal(format!("{}.{}", name, index), false)
+            }
+            Segment::Constant => self.write_load_literal(format!("{}", index), true),
+            Segment::This => self.write_load_segment("THIS", index),
+            Segment::That => self.write_load_segment("THAT", index),
+            Segment::Pointer => self.write_load_literal(format!("{}", 3 + index), false),
+            Segment::Temp => self.write_load_literal(format!("{}", 5 + index), false),
+        }
+    }
+
+    pub fn write_pop(&mut self, segment: Segment, index: u16) -> io::Result<()> {
+        // Stepping A up to a small index is shorter than going through R13
+        let stepped = (self.compact || self.cache_top) && index <= 5;
+        if let (Some(register), true) = (base_register(segment), stepped) {
+            self.load_top()?;
+            self.cached = false;
+            write!(self.out_file, "@{} \nA=M \n", register)?;
+            for _ in 0..index {
+                self.out_file.write_all(b"A=A+1 \n")?;
+            }
+            return self.out_file.write_all(b"M=D \n");
+        }
+
+        if self.cache_top {
+            let location = match segment {
+                Segment::Static => Some(format!("{}.{}", self.file_name, index)),
+                Segment::Pointer => Some(format!("{}", 3 + index)),
+                Segment::Temp => Some(format!("{}", 5 + index)),
+                _ => None,
+            };
+            if let Some(location) = location {
+                self.load_top()?;
+                self.cached = false;
+                return self.write_copy_to_temp(location);
+            }
+            self.flush()?;
+        }
+
+        match segment {
+            Segment::Argument => self.write_temp_offset("ARG", index)?,
+            Segment::Local => self.write_temp_offset("LCL", index)?,
+            Segment::This => self.write_temp_offset("THIS", index)?,
+            Segment::That => self.write_temp_offset("THAT", index)?,
+            _ => (),
+        }
+        self.out_file.write_all(b"@SP \n\
+                                  AM=M-1 \n\
+                                  D=M \n")?;
+        match segment {
+            Segment::Argument | Segment::Local | Segment::This | Segment::That => {
+                self.out_file.write_all(b"@R13 \n\
+                                          A=M \n\
+                                          M=D \n")
+            }
+            Segment::Static => {
+                let name = self.file_name.clone();
+                self.write_copy_to_temp(format!("{}.{}", name, index))
+            }
+            Segment::Pointer => self.write_copy_to_temp(format!("{}", 3 + index)),
+            Segment::Temp => self.write_copy_to_temp(format!("{}", 5 + index)),
+            Segment::Constant => panic!("Cannot pop to the constant segment"),
+        }
+    }
+
+    fn write_push_and_increment(&mut self) -> io::Result<()> {
+        if self.compact {
+            return self.out_file.write_all(b"@SP \n\
+                                              AM=M+1 \n\
+                                              A=A-1 \n\
+                                              M=D \n");
+        }
+        self.out_file.write_all(b"@SP \n\
+                                  A=M \n\
+                                  M=D \n\
+                                  @SP \n\
+                                  M=M+1\n")
+    }
+
+    fn write_copy_to_temp(&mut self, location: String) -> io::Result<()> {
+        write!(self.out_file, "@{} \nM=D \n", location)
+    }
+
+    fn write_temp_offset(&mut self, segment: &str, index: u16) -> io::Result<()> {
+        write!(self.out_file,
+               "@{} \nD=M \n@{} \nD=D+A \n@R13 \nM=D \n",
+               segment,
+               index)
+    }
+
+    fn write_load_segment(&mut self, segment: &str, index: u16) -> io::Result<()> {
+        if self.compact && index <= 1 {
+            let step = if index == 1 { "A=A+1 \n" } else { "" };
+            return write!(self.out_file, "@{} \nA=M \n{}D=M\n", segment, step);
+        }
+        write!(self.out_file,
+               "@{} \nD=M \n@{} \nA=D+A \nD=M\n",
+               segment,
+               index)
+    }
+
+    fn write_load_literal(&mut self, location: String, direct: bool) -> io::Result<()> {
+        writeln!(self.out_file, "@{}", location)?;
+
+        if direct {
+            self.out_file.write_all(b"D=A\n")
+        } else {
+            self.out_file.write_all(b"D=M\n")
+        }
+    }
+
+    pub fn write_label(&mut self, label: &str) -> io::Result<()> {
+        self.flush()?;
+        writeln!(self.out_file, "({}${})", self.function_name, label)
+    }
+
+    pub fn write_goto(&mut self, label: &str) -> io::Result<()> {
+        self.flush()?;
+        write!(self.out_file, "@{}${} \n\
+                               0;JMP \n", self.function_name, label)
+    }
+
+    pub fn write_if(&mut self, label: &str) -> io::Result<()> {
+        if self.cache_top {
+            self.load_top()?;
+            self.cached = false;
+            return write!(self.out_file, "@{}${} \nD;JNE \n", self.function_name, label);
+        }
+        write!(self.out_file, "@SP \n\
+                               AM=M-1 \n\
+                               D=M \n\
+                               @{}${} \n\
+                               D;JNE \n", self.function_name, label)
+    }
+
+    pub fn write_function(&mut self, function_name: &str, num_locals: u16) -> io::Result<()> {
+        self.write_function_label(function_name, num_locals)?;
+        self.write_locals(num_locals)
+    }
+
+    fn write_function_label(&mut self, function_name: &str, num_locals: u16) -> io::Result<()> {
+        self.flush()?;
+        self.function_name = function_name.to_string();
+        self.functions.push(function_name.to_string());
+        self.num_locals = num_locals;
+        writeln!(self.out_file, "({})", function_name)
+    }
+
+    fn write_locals(&mut self, num_locals: u16) -> io::Result<()> {
+        if num_locals != 0 {
+            self.out_file.write_all(b"@SP \n\
+                                      A=M \n\
+                                      M=0\n")?;
+
+            for _ in 1..num_locals {
+                self.out_file.write_all(b"A=A+1 \n\
+                                          M=0\n")?;
+            }
+
+            write!(self.out_file, "@{} \nD=A \n@SP \nM=D+M \n", num_locals)?;
+        }
+        Ok(())
+    }
+
+    pub fn write_return(&mut self) -> io::Result<()> {
+        self.flush()?;
+        if self.compact {
+            self.use_routine("RETURN".to_string());
+            return self.out_file.write_all(b"@__RETURN \n\
+                                              0;JMP \n");
+        }
+        self.write_return_body()
+    }
+
+    fn write_return_body(&mut self) -> io::Result<()> {
+        // FRAME(R14) = LCL
+        // RET(R15) = *(FRAME-5)
+        self.out_file.write_all(b"@LCL \n\
+                                  D=M \n\
+                                  @R14 \n\
+                                  M=D \n\
+                                  @5 \n\
+                                  A=D-A \n\
+                                  D=M \n\
+                                  @R15 \n\
+                                  M=D \n")?;
+
+        // *ARG = pop()
+        self.out_file.write_all(b"@SP \n\
+                                  A=M-1 \n\
+                                  D=M \n\
+                                  @ARG \n\
+                                  A=M \n\
+                                  M=D \n")?;
+
+        // SP = ARG+1
+        self.out_file.write_all(b"@ARG \n\
+                                  D=M+1 \n\
+                                  @SP \n\
+                                  M=D \n")?;
+
+        // Restore THAT, THIS, ARG and LCL
+        self.write_restore("THAT")?;
+        self.write_restore("THIS")?;
+        self.write_restore("ARG")?;
+        self.write_restore("LCL")?;
+
+        // go to return
+        self.out_file.write_all(b"@R15 \n\
+                                  A=M \n\
+                                  0;JMP \n")
+    }
+
+    fn write_restore(&mut self, register: &str) -> io::Result<()> {
+        write!(self.out_file,
+               "@R14 \nAM=M-1 \nD=M \n@{} \nM=D \n",
+               register)
+    }
+
+    pub fn write_call(&mut self, function_name: &str, num_args: u16) -> io::Result<()> {
+        self.flush()?;
+        if self.compact {
+            // R14 = f, D = return-address, one routine per n
+            write!(self.out_file,
+                   "@{} \n\
+                    D=A \n\
+                    @R14 \n\
+                    M=D \n", function_name)?;
+            return self.write_routine_jump(format!("CALL{}", num_args), "CALL");
+        }
+
+        // Push return-address
+        write!(self.out_file, "@CALL{} \nD=A \n", self.counter)?;
+        self.write_push_and_increment()?;
+        self.write_call_frame()?;
+
+        // ARG=SP-n-5
+        write!(self.out_file,
+               "@{} \n\
+                D=A \n\
+                @SP \n\
+                D=M-D \n\
+                @ARG \n\
+                M=D \n",num_args+5)?;
+
+        // LCL=SP
+        self.out_file.write_all(b"@SP \n\
+                                  D=M \n\
+                                  @LCL \n\
+                                  M=D \n")?;
+
+        // goto f and return
+        write!(self.out_file,
+               "@{} \n\
+                0;JMP \n\
+                (CALL{}) \n", function_name, self.counter)?;
+
+        self.counter += 1;
+        Ok(())
+    }
+
+    /// Writes `call function_name num_args` followed by `return` as a jump
+    /// that reuses the current function's frame, so tail-recursive code
+    /// runs in constant stack space. The arguments replace the current
+    /// function's and its saved frame moves up to just above them, so the
+    /// callee returns straight to the current function's caller. A
+    /// function calling itself with as many arguments as it was given
+    /// keeps its frame where it is and only moves the arguments.
+    pub fn write_tail_call(&mut self, function_name: &str, num_args: u16) -> io::Result<()> {
+        if self.functions.is_empty() {
+            self.write_call(function_name, num_args)?;
+            return self.write_return();
+        }
+        self.flush()?;
+
+        if function_name == self.function_name {
+            // LCL - ARG - 5 is the number of arguments it was given
+            write!(self.out_file,
+                   "@LCL \n\
+                    D=M \n\
+                    @ARG \n\
+                    D=D-M \n\
+                    @{} \n\
+                    D=D-A \n\
+                    @TAIL{} \n\
+                    D;JNE \n", num_args + 5, self.counter)?;
+
+            for i in 0..num_args {
+                write!(self.out_file,
+                       "@ARG \n\
+                        D=M \n\
+                        @{} \n\
+                        D=D+A \n\
+                        @R14 \n\
+                        M=D \n\
+                        @SP \n\
+                        D=M \n\
+                        @{} \n\
+                        A=D-A \n\
+                        D=M \n\
+                        @R14 \n\
+                        A=M \n\
+                        M=D \n", i, num_args - i)?;
+            }
+
+            // Drop the locals and stack, and start over
+            write!(self.out_file,
+                   "@LCL \n\
+                    D=M \n\
+                    @SP \n\
+                    M=D \n\
+                    @{} \n\
+                    0;JMP \n\
+                    (TAIL{}) \n", function_name, self.counter)?;
+            self.counter += 1;
+        }
+
+        // R13 = n, R14 = f
+        write!(self.out_file,
+               "@{} \n\
+                D=A \n\
+                @R13 \n\
+                M=D \n\
+                @{} \n\
+                D=A \n\
+                @R14 \n\
+                M=D \n\
+                @__TAIL_CALL \n\
+                0;JMP \n", num_args, function_name)?;
+        self.use_routine("TAIL_CALL".to_string());
+        Ok(())
+    }
+
+    // Moves R13 arguments and the current frame down to ARG and jumps to R14
+    fn write_tail_call_body(&mut self) -> io::Result<()> {
+        // Push the saved return address, LCL, ARG, THIS and THAT, so they
+        // and the arguments lie together at the top of the stack
+        for offset in (1..6).rev() {
+            write!(self.out_file,
+                   "@LCL \n\
+                    D=M \n\
+                    @{} \n\
+                    A=D-A \n\
+                    D=M \n", offset)?;
+            self.write_push_and_increment()?;
+        }
+
+        // R13 = SP-n-5, the first word to move, R15 = ARG, where it goes
+        self.out_file.write_all(b"@R13 \n\
+                                  D=M \n\
+                                  @5 \n\
+                                  D=D+A \n\
+                                  @SP \n\
+                                  D=M-D \n\
+                                  @R13 \n\
+                                  M=D \n\
+                                  @ARG \n\
+                                  D=M \n\
+                                  @R15 \n\
+                                  M=D \n")?;
+
+        // Move words up to SP. The target is always below the source.
+        self.out_file.write_all(b"(__TAIL_CALL_LOOP) \n\
+                                  @R13 \n\
+                                  A=M \n\
+                                  D=M \n\
+                                  @R15 \n\
+                                  A=M \n\
+                                  M=D \n\
+                                  @R15 \n\
+                                  M=M+1 \n\
+                                  @R13 \n\
+                                  MD=M+1 \n\
+                                  @SP \n\
+                                  D=D-M \n\
+                                  @__TAIL_CALL_LOOP \n\
+                                  D;JNE \n")?;
+
+        // SP = LCL = R15, ARG stays, goto f
+        self.out_file.write_all(b"@R15 \n\
+                                  D=M \n\
+                                  @SP \n\
+                                  M=D \n\
+                                  @LCL \n\
+                                  M=D \n\
+                                  @R14 \n\
+                                  A=M \n\
+                                  0;JMP \n")
+    }
+
+    fn write_call_frame(&mut self) -> io::Result<()> {
+        // Push LCL
+        self.out_file.write_all(b"@LCL \n\
+                                  D=M \n")?;
+        self.write_push_and_increment()?;
+
+        // Push ARG
+        self.out_file.write_all(b"@ARG \n\
+                                  D=M \n")?;
+        self.write_push_and_increment()?;
+
+        // Push THIS
+        self.out_file.write_all(b"@THIS \n\
+                                  D=M \n")?;
+        self.write_push_and_increment()?;
+
+        // Push THAT
+        self.out_file.write_all(b"@THAT \n\
+                                  D=M \n")?;
+        self.write_push_and_increment()
+    }
+
+    // Jumps to a shared routine with the address to come back to in D
+    fn write_routine_jump(&mut self, routine: String, return_label: &str) -> io::Result<()> {
+        write!(self.out_file,
+               "@{1}{2} \n\
+                D=A \n\
+                @__{0} \n\
+                0;JMP \n\
+                ({1}{2}) \n", routine, return_label, self.counter)?;
+        self.use_routine(routine);
+
+        self.counter += 1;
+        Ok(())
+    }
+
+    fn use_routine(&mut self, routine: String) {
+        if !self.routines.contains(&routine) {
+            self.routines.push(routine);
+        }
+    }
+
+    /// Writes the shared routines compact code has jumped to so far.
+    pub fn write_runtime(&mut self) -> io::Result<()> {
+        self.flush()?;
+        for routine in self.routines.clone() {
+            writeln!(self.out_file, "(__{})", routine)?;
+
+            if let Some(num_args) = routine.strip_prefix("CALL") {
+                self.write_push_and_increment()?;
+                self.write_call_frame()?;
+
+                // ARG=SP-n-5, LCL=SP, goto R14
+                write!(self.out_file,
+                       "@{} \n\
+                        D=A \n\
+                        @SP \n\
+                        D=M-D \n\
+                        @ARG \n\
+                        M=D \n\
+                        @SP \n\
+                        D=M \n\
+                        @LCL \n\
+                        M=D \n\
+                        @R14 \n\
+                        A=M \n\
+                        0;JMP \n", num_args.parse::<u16>().unwrap() + 5)?;
+                continue;
+            }
+
+            // R14 = function ID, D = error code
+            if let Some((code, id)) = routine.strip_prefix("FAIL").and_then(|rest| rest.split_once('_')) {
+                write!(self.out_file,
+                       "@{} \n\
+                        D=A \n\
+                        @R14 \n\
+                        M=D \n\
+                        @{} \n\
+                        D=A \n\
+                        @__FAILED \n\
+                        0;JMP \n", id, code)?;
+                continue;
+            }
+
+            match routine.as_ref() {
+                "RETURN" => self.write_return_body()?,
+                "TAIL_CALL" => self.write_tail_call_body()?,
+                "MUL" => self.write_multiply()?,
+                "DIV" => self.write_divide()?,
+                "SHL" => self.write_shift_left()?,
+                "SHR" => self.write_shift_right()?,
+                // R13 = error code, then halt
+                "FAILED" => {
+                    self.out_file.write_all(b"@R13 \n\
+                                              M=D \n\
+                                              (__FAILED_HALT) \n\
+                                              @__FAILED_HALT \n\
+                                              0;JMP \n")?;
+                }
+                "GT" | "LT" => {
+                    let op = if routine == "GT" { ArithOp::Gt } else { ArithOp::Lt };
+                    self.out_file.write_all(b"@R15 \n\
+                                              M=D \n\
+                                              @SP \n\
+                                              AM=M-1 \n\
+                                              D=M \n")?;
+                    self.write_signed_compare(op, &format!("__{}", routine))?;
+                    self.out_file.write_all(b"@SP \n\
+                                              A=M-1 \n\
+                                              M=D \n\
+                                              @R15 \n\
+                                              A=M \n\
+                                              0;JMP \n")?;
+                }
+                _ => {
+                    // Store false, then true unless x - y is nonzero
+                    write!(self.out_file,
+                           "@R15 \n\
+                            M=D \n\
+                            @SP \n\
+                            AM=M-1 \n\
+                            D=M \n\
+                            A=A-1 \n\
+                            D=M-D \n\
+                            M=0 \n\
+                            @__{0}_END \n\
+                            D;JNE \n\
+                            @SP \n\
+                            A=M-1 \n\
+                            M=-1 \n\
+                            (__{0}_END) \n\
+                            @R15 \n\
+                            A=M \n\
+                            0;JMP \n", routine)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // x * y by shift and add: for each bit of y, clearing it as it goes,
+    // add x shifted to that bit. R13 = bit, R14 = shifted x, and y stays
+    // in its stack slot.
+    fn write_multiply(&mut self) -> io::Result<()> {
+        self.out_file.write_all(b"@R15 \n\
+                                  M=D \n\
+                                  @SP \n\
+                                  AM=M-1 \n\
+                                  A=A-1 \n\
+                                  D=M \n\
+                                  M=0 \n\
+                                  @R14 \n\
+                                  M=D \n\
+                                  @R13 \n\
+                                  M=1 \n\
+                                  (__MUL_LOOP) \n\
+                                  @SP \n\
+                                  A=M \n\
+                                  D=M \n\
+                                  @__MUL_END \n\
+                                  D;JEQ \n\
+                                  @R13 \n\
+                                  D=D&M \n\
+                                  @__MUL_NEXT \n\
+                                  D;JEQ \n\
+                                  @SP \n\
+                                  A=M \n\
+                                  M=M-D \n\
+                                  @R14 \n\
+                                  D=M \n\
+                                  @SP \n\
+                                  A=M-1 \n\
+                                  M=D+M \n\
+                                  (__MUL_NEXT) \n\
+                                  @R14 \n\
+                                  D=M \n\
+                                  M=D+M \n\
+                                  @R13 \n\
+                                  D=M \n\
+                                  M=D+M \n\
+                                  @__MUL_LOOP \n\
+                                  0;JMP \n\
+                                  (__MUL_END) \n\
+                                  @R15 \n\
+                                  A=M \n\
+                                  0;JMP \n")
+    }
+
+    // x / y by long division of |x| by |y|, one bit at a time from the
+    // top. R13 = |y|, R14 = remainder, |x| is shifted left in y's slot and
+    // the quotient builds up in x's. A negative result is noted by
+    // inverting the return address in R15, which is never negative itself.
+    fn write_divide(&mut self) -> io::Result<()> {
+        // |y| and |x|, with 0 for the quotient
+        self.out_file.write_all(b"@R15 \n\
+                                  M=D \n\
+                                  @SP \n\
+                                  AM=M-1 \n\
+                                  D=M \n\
+                                  @R13 \n\
+                                  M=D \n\
+                                  @__DIV_Y_POSITIVE \n\
+                                  D;JGE \n\
+                                  @R13 \n\
+                                  M=-M \n\
+                                  @R15 \n\
+                                  M=!M \n\
+                                  (__DIV_Y_POSITIVE) \n\
+                                  @SP \n\
+                                  A=M-1 \n\
+                                  D=M \n\
+                                  @__DIV_X_POSITIVE \n\
+                                  D;JGE \n\
+                                  D=-D \n\
+                                  @R15 \n\
+                                  M=!M \n\
+                                  (__DIV_X_POSITIVE) \n\
+                                  @SP \n\
+                                  A=M \n\
+                                  M=D \n\
+                                  A=A-1 \n\
+                                  M=0 \n")?;
+
+        // y = -32768 divides only x = -32768, y = 0 gives 0 and y = 1 |x|
+        self.out_file.write_all(b"@R13 \n\
+                                  D=M \n\
+                                  @__DIV_MIN \n\
+                                  D;JLT \n\
+                                  @__DIV_SIGN \n\
+                                  D;JEQ \n\
+                                  D=D-1 \n\
+                                  @__DIV_ONE \n\
+                                  D;JEQ \n")?;
+
+        // The top bit of |x| is less than |y|, so starts the remainder and
+        // leaves a quotient bit of 0. A 1 in its place marks where the
+        // quotient ends up once the other 15 bits are shifted in.
+        self.out_file.write_all(b"@SP \n\
+                                  A=M-1 \n\
+                                  M=1 \n\
+                                  @R14 \n\
+                                  M=0 \n\
+                                  @SP \n\
+                                  A=M \n\
+                                  D=M \n\
+                                  M=D+M \n\
+                                  @__DIV_LOOP \n\
+                                  D;JGE \n\
+                                  @R14 \n\
+                                  M=1 \n")?;
+
+        // Shift the next bit of |x| into the remainder, and subtract |y|
+        // from it if it fits, which is certain once its top bit is set
+        self.out_file.write_all(b"(__DIV_LOOP) \n\
+                                  @R14 \n\
+                                  D=M \n\
+                                  M=D+M \n\
+                                  @SP \n\
+                                  A=M-1 \n\
+                                  D=M \n\
+                                  M=D+M \n\
+                                  @SP \n\
+                                  A=M \n\
+                                  D=M \n\
+                                  M=D+M \n\
+                                  @__DIV_COMPARE \n\
+                                  D;JGE \n\
+                                  @R14 \n\
+                                  M=M+1 \n\
+                                  (__DIV_COMPARE) \n\
+                                  @R14 \n\
+                                  D=M \n\
+                                  @__DIV_SUBTRACT \n\
+                                  D;JLT \n\
+                                  @R13 \n\
+                                  D=D-M \n\
+                                  @__DIV_NEXT \n\
+                                  D;JLT \n\
+                                  (__DIV_SUBTRACT) \n\
+                                  @R13 \n\
+                                  D=M \n\
+                                  @R14 \n\
+                                  M=M-D \n\
+                                  @SP \n\
+                                  A=M-1 \n\
+                                  M=M+1 \n\
+                                  (__DIV_NEXT) \n\
+                                  @SP \n\
+                                  A=M-1 \n\
+                                  D=M \n\
+                                  @__DIV_LOOP \n\
+                                  D;JGE \n\
+                                  @32767 \n\
+                                  D=A \n\
+                                  @SP \n\
+                                  A=M-1 \n\
+                                  M=D&M \n\
+                                  @__DIV_SIGN \n\
+                                  0;JMP \n")?;
+
+        self.out_file.write_all(b"(__DIV_MIN) \n\
+                                  @SP \n\
+                                  A=M \n\
+                                  D=M \n\
+                                  @__DIV_SIGN \n\
+                                  D;JGE \n\
+                                  @SP \n\
+                                  A=M-1 \n\
+                                  M=1 \n\
+                                  @__DIV_SIGN \n\
+                                  0;JMP \n\
+                                  (__DIV_ONE) \n\
+                                  @SP \n\
+                                  A=M \n\
+                                  D=M \n\
+                                  A=A-1 \n\
+                                  M=D \n\
+                                  (__DIV_SIGN) \n\
+                                  @R15 \n\
+                                  D=M \n\
+                                  @__DIV_END \n\
+                                  D;JGE \n\
+                                  @R15 \n\
+                                  M=!M \n\
+                                  @SP \n\
+                                  A=M-1 \n\
+                                  M=-M \n\
+                                  (__DIV_END) \n\
+                                  @R15 \n\
+                                  A=M \n\
+                                  0;JMP \n")
+    }
+
+    // x << y, doubling x until y runs out or x is 0. R13 = bits left.
+    fn write_shift_left(&mut self) -> io::Result<()> {
+        self.out_file.write_all(b"@R15 \n\
+                                  M=D \n\
+                                  @SP \n\
+                                  AM=M-1 \n\
+                                  D=M \n\
+                                  @R13 \n\
+                                  M=D \n\
+                                  (__SHL_LOOP) \n\
+                                  @R13 \n\
+                                  D=M \n\
+                                  @__SHL_END \n\
+                                  D;JEQ \n\
+                                  @SP \n\
+                                  A=M-1 \n\
+                                  D=M \n\
+                                  @__SHL_END \n\
+                                  D;JEQ \n\
+                                  @SP \n\
+                                  A=M-1 \n\
+                                  M=D+M \n\
+                                  @R13 \n\
+                                  M=M-1 \n\
+                                  @__SHL_LOOP \n\
+                                  0;JMP \n\
+                                  (__SHL_END) \n\
+                                  @R15 \n\
+                                  A=M \n\
+                                  0;JMP \n")
+    }
+
+    // x >> y, copying each bit of x from y up down to the bottom, as the
+    // Hack ALU cannot shift right. R13 = bit read, R14 = bit written, and
+    // x moves to y's slot.
+    fn write_shift_right(&mut self) -> io::Result<()> {
+        // R13 = 1 << y, or 0 once y reaches 16
+        self.out_file.write_all(b"@R15 \n\
+                                  M=D \n\
+                                  @SP \n\
+                                  AM=M-1 \n\
+                                  D=M \n\
+                                  @R14 \n\
+                                  M=D \n\
+                                  @SP \n\
+                                  A=M-1 \n\
+                                  D=M \n\
+                                  M=0 \n\
+                                  A=A+1 \n\
+                                  M=D \n\
+                                  @R13 \n\
+                                  M=1 \n\
+                                  (__SHR_FIND) \n\
+                                  @R14 \n\
+                                  D=M \n\
+                                  @__SHR_FOUND \n\
+                                  D;JEQ \n\
+                                  @R13 \n\
+                                  D=M \n\
+                                  @__SHR_FOUND \n\
+                                  D;JEQ \n\
+                                  @R13 \n\
+                                  M=D+M \n\
+                                  @R14 \n\
+                                  M=M-1 \n\
+                                  @__SHR_FIND \n\
+                                  0;JMP \n\
+                                  (__SHR_FOUND) \n\
+                                  @R14 \n\
+                                  M=1 \n")?;
+
+        self.out_file.write_all(b"(__SHR_LOOP) \n\
+                                  @R13 \n\
+                                  D=M \n\
+                                  @__SHR_END \n\
+                                  D;JEQ \n\
+                                  @SP \n\
+                                  A=M \n\
+                                  D=D&M \n\
+                                  @__SHR_NEXT \n\
+                                  D;JEQ \n\
+                                  @R14 \n\
+                                  D=M \n\
+                                  @SP \n\
+                                  A=M-1 \n\
+                                  M=D|M \n\
+                                  (__SHR_NEXT) \n\
+                                  @R13 \n\
+                                  D=M \n\
+                                  M=D+M \n\
+                                  @R14 \n\
+                                  D=M \n\
+                                  M=D+M \n\
+                                  @__SHR_LOOP \n\
+                                  0;JMP \n\
+                                  (__SHR_END) \n\
+                                  @R15 \n\
+                                  A=M \n\
+                                  0;JMP \n")
+    }
+
+    /// Ends the program in an infinite loop, followed by the shared
+    /// routines, and flushes the output.
+    pub fn close(&mut self) -> io::Result<()> {
+        self.flush()?;
+        write!(self.out_file, "(END) \n@END \n0;JMP\n")?;
+        self.write_runtime()?;
+        self.out_file.flush()
+    }
+}
+
+fn base_register(segment: Segment) -> Option<&'static str> {
+    match segment {
+        Segment::Argument => Some("ARG"),
+        Segment::Local => Some("LCL"),
+        Segment::This => Some("THIS"),
+        Segment::That => Some("THAT"),
+        _ => None,
+    }
+}
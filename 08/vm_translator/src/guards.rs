@@ -0,0 +1,129 @@
+//! Plans the runtime checks `--checked` code makes. Rather than guarding
+//! every push and pop, each straight run of commands checks up front that
+//! the stack has room for the most it will push and holds the most it will
+//! pop, so a failing check means the run would go on to overflow or
+//! underflow.
+
+use vm_command::{ArithOp, Segment, VmCommand};
+
+/// A check made before a command runs
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Guard {
+    /// This many more words must fit on the stack below the heap at 2048
+    Overflow(u16),
+    /// This many words must be on the stack above the current function's
+    /// locals, or above 256 outside any function
+    Underflow(u16),
+    /// The `this` or `that` pointer must not be 0
+    Null(Segment),
+    /// The divisor on top of the stack must not be 0
+    DivideByZero,
+}
+
+impl Guard {
+    /// Code the failing check leaves in R13
+    pub fn error_code(self) -> u16 {
+        match self {
+            Guard::Overflow(_) => 1,
+            Guard::Underflow(_) => 2,
+            Guard::Null(_) => 3,
+            Guard::DivideByZero => 4,
+        }
+    }
+}
+
+/// Guards to write before each of a file's commands. Those of a `function`
+/// go between its label and the code that sets up its locals, and cover
+/// the locals too.
+pub fn plan(commands: &[(usize, VmCommand)]) -> Vec<Vec<Guard>> {
+    let mut guards = vec![Vec::new(); commands.len()];
+    // Whether THIS and THAT have been checked since they last changed
+    let mut this_checked = false;
+    let mut that_checked = false;
+    let mut start = 0;
+
+    while start < commands.len() {
+        // A run ends after a jump or `return`, or before a label or
+        // `function`, as other paths may join there
+        let mut end = start;
+        while end < commands.len() {
+            end += 1;
+            match commands[end - 1].1 {
+                VmCommand::Goto(_) | VmCommand::IfGoto(_) | VmCommand::Return => break,
+                _ => (),
+            }
+            if let Some((_, VmCommand::Label(_))) | Some((_, VmCommand::Function(..))) = commands.get(end) {
+                break;
+            }
+        }
+
+        // Depth relative to the start of the run, the most it goes above
+        // that and the most it needs below it
+        let mut depth = 0i32;
+        let mut highest = 0;
+        let mut lowest = 0;
+        let mut locals = 0;
+        let mut first = None;
+        for (i, (_, command)) in commands.iter().enumerate().take(end).skip(start) {
+            let (popped, peak, pushed) = match *command {
+                VmCommand::Arithmetic(op) => (op.arity() as i32, 0, 1),
+                VmCommand::Push(..) => (0, 1, 1),
+                VmCommand::Pop(..) | VmCommand::IfGoto(_) | VmCommand::Return => (1, 0, 0),
+                // The return address and the caller's LCL, ARG, THIS and THAT
+                VmCommand::Call(_, num_args) => (num_args as i32, 5, 1),
+                VmCommand::Function(_, num_locals) => {
+                    locals = num_locals;
+                    this_checked = false;
+                    that_checked = false;
+                    continue;
+                }
+                VmCommand::Label(_) => {
+                    this_checked = false;
+                    that_checked = false;
+                    continue;
+                }
+                VmCommand::Goto(_) => continue,
+            };
+            first.get_or_insert(i);
+
+            lowest = lowest.max(popped - depth);
+            highest = highest.max(depth + peak);
+            depth += pushed - popped;
+
+            match *command {
+                VmCommand::Arithmetic(ArithOp::Div) => guards[i].push(Guard::DivideByZero),
+                VmCommand::Pop(Segment::Pointer, 0) => this_checked = false,
+                VmCommand::Pop(Segment::Pointer, _) => that_checked = false,
+                VmCommand::Push(segment, _) | VmCommand::Pop(segment, _) => {
+                    let checked = match segment {
+                        Segment::This => &mut this_checked,
+                        Segment::That => &mut that_checked,
+                        _ => continue,
+                    };
+                    if !*checked {
+                        guards[i].push(Guard::Null(segment));
+                        *checked = true;
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        // Locals are set up after the label, so are checked there along
+        // with the rest of the run
+        let overflow = (highest as u16).saturating_add(locals);
+        if let VmCommand::Function(..) = commands[start].1 {
+            if overflow > 0 {
+                guards[start].push(Guard::Overflow(overflow));
+            }
+        } else if let (Some(i), true) = (first, overflow > 0) {
+            guards[i].insert(0, Guard::Overflow(overflow));
+        }
+        if let (Some(i), true) = (first, lowest > 0) {
+            guards[i].insert(0, Guard::Underflow(lowest as u16));
+        }
+
+        start = end;
+    }
+    guards
+}
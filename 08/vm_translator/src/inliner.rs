@@ -0,0 +1,191 @@
+//! Whole-program inlining of calls to small leaf functions, such as the
+//! getters and setters of Jack classes, whose few commands cost far less
+//! than the call and return around them.
+
+use std::collections::BTreeMap;
+
+use vm_command::{Segment, VmCommand};
+
+/// Most commands a function may have, besides `function` and `return`,
+/// for calls to it to be inlined
+pub const MAX_SIZE: usize = 10;
+
+// A function calls to which can be inlined: no calls, labels or jumps, and
+// one `return` at the end with a single value on the stack
+struct Leaf {
+    // Index of the file defining it
+    file: usize,
+    body: Vec<VmCommand>,
+    // Arguments the body uses, from the highest index
+    arguments: u16,
+    // Locals it declares or uses, whichever is more
+    locals: u16,
+    uses_statics: bool,
+    // Pointers the body sets, which the caller gets back on return
+    pointers: Vec<u16>,
+}
+
+/// Replaces each call to a small leaf function with the function's body.
+/// Its arguments, locals and the caller's saved `this` and `that` pointers
+/// go in new locals of the caller, shared by every call inlined there.
+/// Calls outside any function, with fewer arguments than the callee uses,
+/// or from another file to a callee using its own statics are left alone.
+pub fn inline(files: &mut [Vec<(usize, VmCommand)>]) {
+    let leaves = leaves(files);
+
+    for (file, commands) in files.iter_mut().enumerate() {
+        let mut inlined = Vec::with_capacity(commands.len());
+        // Index in `inlined` of the current function's `function`
+        // command, and the locals it declares
+        let mut current: Option<(usize, u16)> = None;
+        let mut extra_locals = 0;
+
+        for (line, command) in commands.drain(..) {
+            if let VmCommand::Function(_, locals) = command {
+                add_locals(&mut inlined, current, extra_locals);
+                current = Some((inlined.len(), locals));
+                extra_locals = 0;
+            }
+
+            if let (Some((_, locals)), &VmCommand::Call(ref name, num_args)) = (current, &command) {
+                if let Some(Some(leaf)) = leaves.get(name) {
+                    let slots = leaf.slots(num_args);
+                    if leaf.arguments <= num_args && (leaf.file == file || !leaf.uses_statics) &&
+                       locals as usize + slots <= Segment::Local.limit() as usize + 1 {
+                        extra_locals = extra_locals.max(slots as u16);
+                        inlined.extend(leaf.expand(locals, num_args).into_iter().map(|command| (line, command)));
+                        continue;
+                    }
+                }
+            }
+            inlined.push((line, command));
+        }
+        add_locals(&mut inlined, current, extra_locals);
+
+        *commands = inlined;
+    }
+}
+
+// Every function defined, with what inlining calls to it takes, or None
+// when they cannot be. Functions defined more than once are not inlined,
+// as which definition a call reaches is not clear.
+fn leaves(files: &[Vec<(usize, VmCommand)>]) -> BTreeMap<String, Option<Leaf>> {
+    let mut leaves = BTreeMap::new();
+    for (file, commands) in files.iter().enumerate() {
+        for (start, (_, command)) in commands.iter().enumerate() {
+            if let VmCommand::Function(ref name, locals) = *command {
+                if leaves.contains_key(name) {
+                    leaves.insert(name.clone(), None);
+                    continue;
+                }
+                let end = commands[start + 1..]
+                    .iter()
+                    .position(|(_, command)| matches!(*command, VmCommand::Function(..)))
+                    .map_or(commands.len(), |length| start + 1 + length);
+                leaves.insert(name.clone(), leaf(file, locals, &commands[start + 1..end]));
+            }
+        }
+    }
+    leaves
+}
+
+// The function with this body, if calls to it can be inlined
+fn leaf(file: usize, locals: u16, commands: &[(usize, VmCommand)]) -> Option<Leaf> {
+    let ((_, last), commands) = commands.split_last()?;
+    if *last != VmCommand::Return || commands.len() > MAX_SIZE {
+        return None;
+    }
+
+    let mut leaf = Leaf {
+        file,
+        body: Vec::new(),
+        arguments: 0,
+        locals,
+        uses_statics: false,
+        pointers: Vec::new(),
+    };
+    let mut depth = 0;
+    for (_, command) in commands {
+        depth += match *command {
+            VmCommand::Arithmetic(op) => 1 - op.arity() as i32,
+            VmCommand::Push(..) => 1,
+            VmCommand::Pop(..) => -1,
+            _ => return None,
+        };
+        if depth < 0 {
+            return None;
+        }
+
+        match *command {
+            VmCommand::Push(Segment::Argument, index) | VmCommand::Pop(Segment::Argument, index) => {
+                leaf.arguments = leaf.arguments.max(index + 1);
+            }
+            VmCommand::Push(Segment::Local, index) | VmCommand::Pop(Segment::Local, index) => {
+                leaf.locals = leaf.locals.max(index + 1);
+            }
+            VmCommand::Push(Segment::Static, _) | VmCommand::Pop(Segment::Static, _) => leaf.uses_statics = true,
+            VmCommand::Pop(Segment::Pointer, index) if !leaf.pointers.contains(&index) => leaf.pointers.push(index),
+            _ => (),
+        }
+        leaf.body.push(command.clone());
+    }
+
+    if depth == 1 {
+        Some(leaf)
+    } else {
+        None
+    }
+}
+
+impl Leaf {
+    // Locals a caller needs for a call with `num_args` arguments
+    fn slots(&self, num_args: u16) -> usize {
+        num_args as usize + self.locals as usize + self.pointers.len()
+    }
+
+    // Commands replacing a call with `num_args` arguments in a function
+    // with `base` locals. The arguments are popped into the first new
+    // locals, followed by the callee's locals and then the saved pointers.
+    fn expand(&self, base: u16, num_args: u16) -> Vec<VmCommand> {
+        let locals = base + num_args;
+        let saved = locals + self.locals;
+        let mut commands = Vec::new();
+
+        for index in (0..num_args).rev() {
+            commands.push(VmCommand::Pop(Segment::Local, base + index));
+        }
+        for index in 0..self.locals {
+            commands.push(VmCommand::Push(Segment::Constant, 0));
+            commands.push(VmCommand::Pop(Segment::Local, locals + index));
+        }
+        for (slot, &pointer) in (saved..).zip(&self.pointers) {
+            commands.push(VmCommand::Push(Segment::Pointer, pointer));
+            commands.push(VmCommand::Pop(Segment::Local, slot));
+        }
+
+        commands.extend(self.body.iter().map(|command| match *command {
+            VmCommand::Push(Segment::Argument, index) => VmCommand::Push(Segment::Local, base + index),
+            VmCommand::Pop(Segment::Argument, index) => VmCommand::Pop(Segment::Local, base + index),
+            VmCommand::Push(Segment::Local, index) => VmCommand::Push(Segment::Local, locals + index),
+            VmCommand::Pop(Segment::Local, index) => VmCommand::Pop(Segment::Local, locals + index),
+            ref command => command.clone(),
+        }));
+
+        // The return value stays on top of the stack
+        for (slot, &pointer) in (saved..).zip(&self.pointers) {
+            commands.push(VmCommand::Push(Segment::Local, slot));
+            commands.push(VmCommand::Pop(Segment::Pointer, pointer));
+        }
+        commands
+    }
+}
+
+// Gives the function whose `function` command is at `current` the extra
+// locals calls inlined into it need
+fn add_locals(commands: &mut [(usize, VmCommand)], current: Option<(usize, u16)>, extra_locals: u16) {
+    if let Some((index, locals)) = current {
+        if let VmCommand::Function(_, ref mut count) = commands[index].1 {
+            *count = locals + extra_locals;
+        }
+    }
+}
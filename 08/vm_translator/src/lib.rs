@@ -0,0 +1,341 @@
+use std::fs::File;
+use std::io;
+use std::io::Read;
+use std::path::Path;
+
+pub mod vm_command;
+use vm_command::VmCommand;
+
+pub mod parser;
+use parser::Parser;
+
+pub mod code_writer;
+use code_writer::CodeWriter;
+
+pub mod optimizer;
+
+pub mod call_graph;
+
+pub mod inliner;
+
+pub mod verifier;
+
+pub mod guards;
+
+pub mod c_writer;
+use c_writer::CWriter;
+
+pub mod x86_writer;
+use x86_writer::X86Writer;
+
+/// A .vm file to translate. `name` is used in diagnostics and source maps,
+/// and its stem names the file's static variables.
+pub struct Source {
+    pub name: String,
+    pub text: String,
+}
+
+pub struct TranslateOptions {
+    /// Start with the bootstrap code that sets SP and calls `Sys.init`
+    pub bootstrap: bool,
+    /// End with an infinite loop so execution never runs off the program
+    pub halt: bool,
+    /// Share one copy of the call, return and comparison code
+    pub compact: bool,
+    /// Keep the top of the stack in D within basic blocks
+    pub cache_top: bool,
+    /// Run the VM optimizer and write `push X; pop Y` as direct moves
+    pub optimize: bool,
+    /// Replace calls to small leaf functions with their bodies
+    pub inline: bool,
+    /// Leave out functions that cannot be reached from `Sys.init`
+    pub remove_unused: bool,
+    /// Precede each command's code with a comment giving its file, line and text
+    pub annotate: bool,
+    /// Refuse programs the verifier finds mistakes in
+    pub verify: bool,
+    /// Halt with an error code on stack overflow, stack underflow, a null
+    /// `this`/`that` or division by zero
+    pub checked: bool,
+    /// Write a `call` just before `return` as a jump that reuses the
+    /// caller's frame
+    pub tail_calls: bool,
+}
+
+impl Default for TranslateOptions {
+    fn default() -> Self {
+        TranslateOptions {
+            bootstrap: true,
+            halt: true,
+            compact: false,
+            cache_top: false,
+            optimize: false,
+            inline: false,
+            remove_unused: false,
+            annotate: false,
+            verify: false,
+            checked: false,
+            tail_calls: false,
+        }
+    }
+}
+
+pub struct Translation {
+    pub asm: String,
+    /// (.asm line, .vm file name, .vm line) where each command starts
+    pub source_map: Vec<(usize, String, usize)>,
+    /// Functions left out by `remove_unused`
+    pub removed_functions: Vec<String>,
+    /// Every function in the order written, so function `i` has ID `i + 1`
+    /// in `checked` error reports
+    pub functions: Vec<String>,
+}
+
+impl Translation {
+    /// Number of instructions the program takes up in ROM
+    pub fn rom_size(&self) -> usize {
+        self.asm
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('(') && !line.starts_with("//"))
+            .count()
+    }
+
+    /// (ROM address, .vm file name, .vm line) where each command starts
+    /// once the assembly is assembled
+    pub fn rom_map(&self) -> Vec<(usize, String, usize)> {
+        // Instructions before each line, counting from line 1
+        let mut addresses = vec![0];
+        for line in self.asm.lines().map(|line| line.trim()) {
+            let is_instruction = !line.is_empty() && !line.starts_with('(') && !line.starts_with("//");
+            let address = addresses[addresses.len() - 1] + is_instruction as usize;
+            addresses.push(address);
+        }
+
+        self.source_map
+            .iter()
+            .map(|&(asm_line, ref vm_file, vm_line)| (addresses[asm_line - 1], vm_file.clone(), vm_line))
+            .collect()
+    }
+}
+
+/// Reads a single .vm file, or every .vm file in a directory sorted by name.
+pub fn read_sources(path: &Path) -> io::Result<Vec<Source>> {
+    let mut paths = if path.is_file() {
+        vec![path.to_path_buf()]
+    } else {
+        let mut paths = Vec::new();
+        for entry in path.read_dir()? {
+            let path = entry?.path();
+            if path.is_file() && path.extension().is_some_and(|ext| ext == "vm") {
+                paths.push(path);
+            }
+        }
+        paths
+    };
+    paths.sort();
+
+    paths.iter()
+        .map(|path| {
+            let mut text = String::new();
+            File::open(path)?.read_to_string(&mut text)?;
+            Ok(Source {
+                name: path.to_str().unwrap().to_string(),
+                text,
+            })
+        })
+        .collect()
+}
+
+/// Parses every source, reporting each malformed command as
+/// `file:line: message`.
+pub fn parse_sources(sources: &[Source]) -> Result<Vec<Vec<(usize, VmCommand)>>, Vec<String>> {
+    let mut parsed = Vec::new();
+    let mut errors = Vec::new();
+
+    for source in sources {
+        let mut parser = Parser::new(&source.text);
+        let mut commands = Vec::new();
+
+        while parser.has_more_commands() {
+            parser.advance();
+            match parser.command() {
+                Ok(command) => commands.push((parser.line_number(), command)),
+                Err(message) => errors.push(format!("{}:{}: {}", source.name, parser.line_number(), message)),
+            }
+        }
+        parsed.push(commands);
+    }
+
+    if errors.is_empty() {
+        Ok(parsed)
+    } else {
+        Err(errors)
+    }
+}
+
+// Each file's commands with their line numbers
+type Commands = Vec<Vec<(usize, VmCommand)>>;
+
+// Parses the sources and verifies, inlines, optimizes and prunes them as
+// `options` ask, giving the commands of each and the functions left out
+fn prepare(sources: &[Source],
+           options: &TranslateOptions)
+           -> Result<(Commands, Vec<String>), Vec<String>> {
+    let mut parsed = parse_sources(sources)?;
+    if options.verify {
+        let errors = verifier::verify(sources, &parsed);
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+    }
+    if options.inline {
+        inliner::inline(&mut parsed);
+    }
+    if options.optimize {
+        parsed = parsed.iter().map(|commands| optimizer::optimize(commands)).collect();
+    }
+    let removed_functions = if options.remove_unused {
+        call_graph::remove_unreachable(&mut parsed, "Sys.init")
+    } else {
+        Vec::new()
+    };
+    Ok((parsed, removed_functions))
+}
+
+/// Translates VM sources into one Hack assembly program.
+pub fn translate(sources: &[Source], options: &TranslateOptions) -> Result<Translation, Vec<String>> {
+    let (parsed, removed_functions) = prepare(sources, options)?;
+
+    let mut code_writer = CodeWriter::new(Vec::new());
+    let mut source_map = Vec::new();
+    code_writer.set_compact(options.compact);
+    code_writer.set_cache_top(options.cache_top);
+
+    // Writing to memory cannot fail
+    if options.bootstrap {
+        code_writer.write_init().unwrap();
+    }
+
+    for (source, commands) in sources.iter().zip(parsed) {
+        let path = Path::new(&source.name);
+        let vm_file = path.file_name().unwrap().to_str().unwrap();
+        code_writer.set_file_name(path.file_stem().unwrap().to_str().unwrap());
+
+        let guards = if options.checked {
+            guards::plan(&commands)
+        } else {
+            vec![Vec::new(); commands.len()]
+        };
+        let mut commands = commands.into_iter().zip(guards).peekable();
+        while let Some(((line, command), guards)) = commands.next() {
+            source_map.push((code_writer.line_number(), vm_file.to_string(), line));
+            if options.annotate {
+                code_writer.write_comment(&format!("{}:{}: {}", vm_file, line, command)).unwrap();
+            }
+
+            if let (true, &VmCommand::Push(segment, index)) = (options.optimize, &command) {
+                if let Some(&((_, VmCommand::Pop(target, target_index)), ref pop_guards)) = commands.peek() {
+                    for &guard in guards.iter().chain(pop_guards) {
+                        code_writer.write_guard(guard).unwrap();
+                    }
+                    code_writer.write_move((segment, index), (target, target_index)).unwrap();
+                    commands.next();
+                    continue;
+                }
+            }
+            if let (true, &VmCommand::Call(ref name, num_args)) = (options.tail_calls, &command) {
+                if let Some(&((return_line, VmCommand::Return), ref return_guards)) = commands.peek() {
+                    for &guard in guards.iter().chain(return_guards) {
+                        code_writer.write_guard(guard).unwrap();
+                    }
+                    code_writer.write_tail_call(name, num_args).unwrap();
+                    // The return has no code of its own, but the VM
+                    // emulator still needs somewhere to resume it at
+                    source_map.push((code_writer.line_number(), vm_file.to_string(), return_line));
+                    commands.next();
+                    continue;
+                }
+            }
+            code_writer.write_guarded(&command, &guards).unwrap();
+        }
+    }
+
+    if options.halt {
+        code_writer.close().unwrap();
+    } else {
+        code_writer.write_runtime().unwrap();
+    }
+
+    let functions = code_writer.functions().to_vec();
+    Ok(Translation {
+        asm: String::from_utf8(code_writer.into_inner()).unwrap(),
+        source_map,
+        removed_functions,
+        functions,
+    })
+}
+
+// Every function the commands define, in order
+fn function_names(parsed: &Commands) -> Vec<String> {
+    parsed.iter()
+        .flat_map(|commands| commands.iter())
+        .filter_map(|(_, command)| match *command {
+            VmCommand::Function(ref name, _) => Some(name.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Translates VM sources into one C program, as described at `CWriter`.
+/// Of the options only `bootstrap`, `optimize`, `inline`,
+/// `remove_unused`, `annotate` and `verify` apply.
+pub fn translate_to_c(sources: &[Source], options: &TranslateOptions) -> Result<String, Vec<String>> {
+    let (parsed, _) = prepare(sources, options)?;
+
+    // Writing to memory cannot fail
+    let mut c_writer = CWriter::new(Vec::new());
+    c_writer.write_init(&function_names(&parsed), options.bootstrap).unwrap();
+
+    for (source, commands) in sources.iter().zip(parsed) {
+        let path = Path::new(&source.name);
+        let vm_file = path.file_name().unwrap().to_str().unwrap();
+        c_writer.set_file_name(path.file_stem().unwrap().to_str().unwrap());
+
+        for (line, command) in commands {
+            if options.annotate {
+                c_writer.write_comment(&format!("{}:{}: {}", vm_file, line, command)).unwrap();
+            }
+            c_writer.write_command(&command).unwrap();
+        }
+    }
+    c_writer.close().unwrap();
+
+    Ok(String::from_utf8(c_writer.into_inner()).unwrap())
+}
+
+/// Translates VM sources into x86-64 assembly, as described at
+/// `X86Writer`, taking the same options as `translate_to_c`.
+pub fn translate_to_x86(sources: &[Source], options: &TranslateOptions) -> Result<String, Vec<String>> {
+    let (parsed, _) = prepare(sources, options)?;
+
+    // Writing to memory cannot fail
+    let mut x86_writer = X86Writer::new(Vec::new());
+    x86_writer.write_init(&function_names(&parsed), options.bootstrap).unwrap();
+
+    for (source, commands) in sources.iter().zip(parsed) {
+        let path = Path::new(&source.name);
+        let vm_file = path.file_name().unwrap().to_str().unwrap();
+        x86_writer.set_file_name(path.file_stem().unwrap().to_str().unwrap());
+
+        for (line, command) in commands {
+            if options.annotate {
+                x86_writer.write_comment(&format!("{}:{}: {}", vm_file, line, command)).unwrap();
+            }
+            x86_writer.write_command(&command).unwrap();
+        }
+    }
+    x86_writer.close().unwrap();
+
+    Ok(String::from_utf8(x86_writer.into_inner()).unwrap())
+}
@@ -0,0 +1,162 @@
+use std::env;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::process;
+
+extern crate getopts;
+use getopts::Options;
+use getopts::ParsingStyle;
+
+extern crate vm_translator;
+use vm_translator::{read_sources, translate, translate_to_c, translate_to_x86, TranslateOptions};
+use vm_translator::c_writer::RUNTIME;
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let program = args[0].clone();
+
+    let mut opts = Options::new();
+    opts.optopt("o", "", "set output file name", "OUTFILE");
+    opts.optopt("t", "target", "write hack assembly (the default), a c program, or x86-64 assembly and a .runtime.c file to link it with", "hack|c|x86-64");
+    opts.optflag("m", "source-map", "write OUTFILE.map relating .asm lines to .vm lines");
+    opts.optflag("", "no-bootstrap", "leave out the code that sets SP and calls Sys.init");
+    opts.optflag("", "no-halt", "leave out the infinite loop at the end of the program");
+    opts.optflag("c", "compact", "share one copy of the call, return and comparison code");
+    opts.optflag("", "cache-top", "keep the top of the stack in D between VM commands");
+    opts.optflag("O", "optimize", "optimize the VM code before translating it");
+    opts.optflag("i", "inline", "replace calls to small leaf functions, such as getters and setters, with their bodies");
+    opts.optflag("r", "remove-unused", "leave out functions Sys.init never calls");
+    opts.optflag("a", "annotate", "comment the code with its .vm source and write OUTFILE.rom.map");
+    opts.optflag("", "verify", "check the whole program for mistakes before translating it");
+    opts.optflag("", "tail-calls", "jump to a function called just before return, reusing the caller's frame");
+    opts.optflag("", "checked", "halt on stack overflow, underflow, null this/that or division by zero, and write OUTFILE.ids");
+    opts.parsing_style(ParsingStyle::FloatingFrees);
+
+    let matches = opts.parse(&args[1..]).expect("Unable to parse arguments.");
+
+    let in_path = if matches.free.len() == 1 {
+        Path::new(&matches.free[0])
+    } else {
+        let brief = format!("Usage: {} FILE [options]", program);
+        println!("{}", opts.usage(&brief));
+        return;
+    };
+
+    let target = matches.opt_str("target").unwrap_or_else(|| "hack".to_string());
+    let extension = match target.as_str() {
+        "hack" => "asm",
+        "c" => "c",
+        "x86-64" => "s",
+        _ => panic!("Unknown target: {}", target),
+    };
+
+    // Set output file
+    let out_file = if matches.opt_present("o") {
+        matches.opt_str("o").unwrap()
+    } else if in_path.is_file() {
+        let mut in_path_buf = in_path.to_path_buf();
+        in_path_buf.set_extension(extension);
+        in_path_buf.to_str().unwrap().to_string()
+    } else {
+        let dir_name = in_path.file_stem().unwrap();
+        let mut in_path_buf = in_path.to_path_buf();
+        in_path_buf.push(dir_name);
+        in_path_buf.set_extension(extension);
+        in_path_buf.to_str().unwrap().to_string()
+    };
+
+    let sources = read_sources(in_path).expect("Unable to read file.");
+    let options = TranslateOptions {
+        bootstrap: !matches.opt_present("no-bootstrap"),
+        halt: !matches.opt_present("no-halt"),
+        compact: matches.opt_present("compact"),
+        cache_top: matches.opt_present("cache-top"),
+        optimize: matches.opt_present("optimize"),
+        inline: matches.opt_present("inline"),
+        remove_unused: matches.opt_present("remove-unused"),
+        annotate: matches.opt_present("annotate"),
+        verify: matches.opt_present("verify"),
+        checked: matches.opt_present("checked"),
+        tail_calls: matches.opt_present("tail-calls"),
+    };
+
+    if target != "hack" {
+        let result = if target == "c" {
+            translate_to_c(&sources, &options)
+        } else {
+            translate_to_x86(&sources, &options)
+        };
+        let program = match result {
+            Ok(program) => program,
+            Err(errors) => {
+                for error in &errors {
+                    eprintln!("{}", error);
+                }
+                process::exit(1);
+            }
+        };
+        let mut out_writer = File::create(&out_file).expect("Unable to create file.");
+        out_writer.write_all(program.as_bytes()).expect("Unable to write to file.");
+
+        if target == "x86-64" {
+            let runtime_file = Path::new(&out_file).with_extension("runtime.c");
+            let mut runtime_writer = File::create(runtime_file).expect("Unable to create file.");
+            runtime_writer.write_all(RUNTIME.as_bytes()).expect("Unable to write to file.");
+        }
+        return;
+    }
+
+    let translation = match translate(&sources, &options) {
+        Ok(translation) => translation,
+        Err(errors) => {
+            for error in &errors {
+                eprintln!("{}", error);
+            }
+            process::exit(1);
+        }
+    };
+
+    if options.remove_unused {
+        for function in &translation.removed_functions {
+            println!("Removed {}", function);
+        }
+        let full = TranslateOptions { remove_unused: false, ..options };
+        let full_size = translate(&sources, &full).expect("Unable to translate.").rom_size();
+        println!("Removed {} functions, saving {} instructions.",
+                 translation.removed_functions.len(), full_size - translation.rom_size());
+    }
+
+    if options.compact {
+        let full = TranslateOptions { compact: false, ..options };
+        let full_size = translate(&sources, &full).expect("Unable to translate.").rom_size();
+        println!("ROM size: {} instructions, {} without --compact.", translation.rom_size(), full_size);
+    }
+
+    let mut out_writer = File::create(&out_file).expect("Unable to create file.");
+    out_writer.write_all(translation.asm.as_bytes()).expect("Unable to write to file.");
+
+    if matches.opt_present("m") {
+        let mut map_file = BufWriter::new(File::create(format!("{}.map", out_file)).expect("Unable to create file."));
+        for (asm_line, vm_file, vm_line) in &translation.source_map {
+            writeln!(map_file, "{} {} {}", asm_line, vm_file, vm_line).expect("Unable to write to file.");
+        }
+        map_file.flush().expect("Unable to write to file.");
+    }
+
+    if options.annotate {
+        let mut map_file = BufWriter::new(File::create(format!("{}.rom.map", out_file)).expect("Unable to create file."));
+        for (address, vm_file, vm_line) in translation.rom_map() {
+            writeln!(map_file, "{} {} {}", address, vm_file, vm_line).expect("Unable to write to file.");
+        }
+        map_file.flush().expect("Unable to write to file.");
+    }
+
+    if options.checked {
+        let mut ids_file = BufWriter::new(File::create(format!("{}.ids", out_file)).expect("Unable to create file."));
+        for (index, function) in translation.functions.iter().enumerate() {
+            writeln!(ids_file, "{} {}", index + 1, function).expect("Unable to write to file.");
+        }
+        ids_file.flush().expect("Unable to write to file.");
+    }
+}
@@ -0,0 +1,250 @@
+//! Peephole optimizations over the commands of one .vm file. Each command
+//! keeps the .vm line it came from, so source maps still point at the
+//! original code.
+
+use vm_command::{ArithOp, Segment, VmCommand};
+
+/// Folds constant arithmetic and branches, removes redundant pairs such as
+/// `not; not` or `push X; pop X`, turns `eq; not; if-goto` into
+/// `sub; if-goto` and `x < c; not; if-goto` into `x > c - 1; if-goto`,
+/// and drops unreachable code after `goto` and `return`, repeating until
+/// nothing changes.
+pub fn optimize(commands: &[(usize, VmCommand)]) -> Vec<(usize, VmCommand)> {
+    let mut commands = commands.to_vec();
+
+    loop {
+        let mut optimized = Vec::new();
+        for command in &commands {
+            optimized.push(command.clone());
+            while simplify_tail(&mut optimized) {}
+        }
+        let optimized = remove_dead_code(&optimized);
+
+        if optimized == commands {
+            return optimized;
+        }
+        commands = optimized;
+    }
+}
+
+// Rewrites the end of `out` after a command was appended, returning whether
+// anything changed
+fn simplify_tail(out: &mut Vec<(usize, VmCommand)>) -> bool {
+    let n = out.len();
+    let (line, last) = out[n - 1].clone();
+
+    match last {
+        VmCommand::Arithmetic(op) => {
+            if n >= 2 && out[n - 2].1 == VmCommand::Arithmetic(op) &&
+               (op == ArithOp::Not || op == ArithOp::Neg) {
+                out.truncate(n - 2);
+                return true;
+            }
+
+            let (b, b_len) = match constant_at(&out[..n - 1]) {
+                Some(constant) => constant,
+                None => return false,
+            };
+            let (value, len) = if op.arity() == 1 {
+                (evaluate(op, 0, b), b_len + 1)
+            } else {
+                match constant_at(&out[..n - 1 - b_len]) {
+                    Some((a, a_len)) => (evaluate(op, a, b), a_len + b_len + 1),
+                    None => return false,
+                }
+            };
+            match value {
+                Some(value) => replace_tail(out, len, constant(value)),
+                None => false,
+            }
+        }
+        VmCommand::IfGoto(ref label) => {
+            if let Some((value, len)) = constant_at(&out[..n - 1]) {
+                let branch = if value != 0 {
+                    vec![VmCommand::Goto(label.clone())]
+                } else {
+                    Vec::new()
+                };
+                return replace_tail(out, len + 1, branch);
+            }
+
+            // x != y is all the branch needs to know
+            if n >= 3 && out[n - 3].1 == VmCommand::Arithmetic(ArithOp::Eq) &&
+               out[n - 2].1 == VmCommand::Arithmetic(ArithOp::Not) {
+                out[n - 3].1 = VmCommand::Arithmetic(ArithOp::Sub);
+                out.remove(n - 2);
+                return true;
+            }
+
+            if n >= 3 && out[n - 2].1 == VmCommand::Arithmetic(ArithOp::Not) {
+                if let Some((negated, len)) = negate_comparison(&out[..n - 2]) {
+                    let branch = out.pop().unwrap();
+                    replace_tail(out, len, negated);
+                    out.push(branch);
+                    return true;
+                }
+            }
+            false
+        }
+        VmCommand::Pop(segment, index) => {
+            if n >= 2 && out[n - 2].1 == VmCommand::Push(segment, index) {
+                out.truncate(n - 2);
+                return true;
+            }
+            false
+        }
+        VmCommand::Label(ref label) => {
+            if n >= 2 && out[n - 2].1 == VmCommand::Goto(label.clone()) {
+                out.remove(n - 2);
+                return true;
+            }
+
+            // not; if-goto L1; goto L2; label L1  =>  if-goto L2; label L1,
+            // which only holds when `not` flips a comparison's true or false
+            if n >= 5 && is_comparison(&out[n - 5].1) &&
+               out[n - 4].1 == VmCommand::Arithmetic(ArithOp::Not) &&
+               out[n - 3].1 == VmCommand::IfGoto(label.clone()) {
+                if let VmCommand::Goto(ref target) = out[n - 2].1.clone() {
+                    let first = out[n - 4].0;
+                    out.truncate(n - 4);
+                    out.push((first, VmCommand::IfGoto(target.clone())));
+                    out.push((line, last.clone()));
+                    return true;
+                }
+            }
+            false
+        }
+        _ => false,
+    }
+}
+
+// Whether `command` leaves only true (-1) or false (0) on the stack
+fn is_comparison(command: &VmCommand) -> bool {
+    match *command {
+        VmCommand::Arithmetic(op) => op == ArithOp::Eq || op == ArithOp::Gt || op == ArithOp::Lt,
+        _ => false,
+    }
+}
+
+// The `lt` or `gt` ending `commands` followed by `not`, written as the
+// opposite comparison against a constant one away, with how many commands
+// that replaces. `x < c; not` is `x > c - 1` and `c < x; not` is
+// `x < c + 1`, moving x first, which only holds when x is a single push.
+// Nothing is rewritten when c is at the end of the range or the result
+// would be no shorter.
+fn negate_comparison(commands: &[(usize, VmCommand)]) -> Option<(Vec<VmCommand>, usize)> {
+    let n = commands.len();
+    let op = match commands[n - 1].1 {
+        VmCommand::Arithmetic(op) if op == ArithOp::Lt || op == ArithOp::Gt => op,
+        _ => return None,
+    };
+    let opposite = |op| if op == ArithOp::Lt { ArithOp::Gt } else { ArithOp::Lt };
+
+    // As `x op c`, with x written out only when it was on the right
+    let (c, len, mut negated, op) = match constant_at(&commands[..n - 1]) {
+        Some((c, c_len)) => (c, c_len, Vec::new(), op),
+        None if n >= 2 => {
+            match commands[n - 2].1 {
+                VmCommand::Push(..) => {
+                    let (c, c_len) = constant_at(&commands[..n - 2])?;
+                    (c, c_len + 1, vec![commands[n - 2].1.clone()], opposite(op))
+                }
+                _ => return None,
+            }
+        }
+        None => return None,
+    };
+    let bound = if op == ArithOp::Lt { c.checked_sub(1) } else { c.checked_add(1) }?;
+    negated.extend(constant(bound));
+    negated.push(VmCommand::Arithmetic(opposite(op)));
+
+    // The comparison and `not` go too
+    let len = len + 2;
+    if negated.len() < len {
+        Some((negated, len))
+    } else {
+        None
+    }
+}
+
+// Replaces the last `len` commands with `commands`, attributed to the line
+// of the first one replaced
+fn replace_tail(out: &mut Vec<(usize, VmCommand)>, len: usize, commands: Vec<VmCommand>) -> bool {
+    let start = out.len() - len;
+    if out[start..].iter().map(|(_, command)| command).eq(commands.iter()) {
+        return false;
+    }
+
+    let line = out[start].0;
+    out.truncate(start);
+    out.extend(commands.into_iter().map(|command| (line, command)));
+    true
+}
+
+// Value of the constant expression ending `commands`, and how many
+// commands it spans
+fn constant_at(commands: &[(usize, VmCommand)]) -> Option<(i16, usize)> {
+    let n = commands.len();
+    match commands.last() {
+        Some(&(_, VmCommand::Push(Segment::Constant, value))) => Some((value as i16, 1)),
+        Some(&(_, VmCommand::Arithmetic(op))) if op.arity() == 1 && n >= 2 => {
+            match commands[n - 2].1 {
+                VmCommand::Push(Segment::Constant, value) => evaluate(op, 0, value as i16).map(|value| (value, 2)),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+// Shortest commands pushing `value`, in the `push constant 0; not` form
+// the Jack compiler uses for `true` when negative
+fn constant(value: i16) -> Vec<VmCommand> {
+    if value >= 0 {
+        vec![VmCommand::Push(Segment::Constant, value as u16)]
+    } else {
+        vec![VmCommand::Push(Segment::Constant, !value as u16), VmCommand::Arithmetic(ArithOp::Not)]
+    }
+}
+
+// Unary operations only look at `b`. Division by zero is left for the
+// program to run into.
+fn evaluate(op: ArithOp, a: i16, b: i16) -> Option<i16> {
+    let truth = |condition: bool| if condition { -1 } else { 0 };
+
+    Some(match op {
+        ArithOp::Add => a.wrapping_add(b),
+        ArithOp::Sub => a.wrapping_sub(b),
+        ArithOp::Neg => b.wrapping_neg(),
+        ArithOp::Eq => truth(a == b),
+        ArithOp::Gt => truth(a > b),
+        ArithOp::Lt => truth(a < b),
+        ArithOp::And => a & b,
+        ArithOp::Or => a | b,
+        ArithOp::Not => !b,
+        ArithOp::Mul => a.wrapping_mul(b),
+        ArithOp::Div if b == 0 => return None,
+        ArithOp::Div => a.wrapping_div(b),
+        ArithOp::Shl | ArithOp::Shr if b as u16 >= 16 => 0,
+        ArithOp::Shl => ((a as u16) << b) as i16,
+        ArithOp::Shr => ((a as u16) >> b) as i16,
+    })
+}
+
+// Drops commands after `goto` or `return` up to the next label or function
+fn remove_dead_code(commands: &[(usize, VmCommand)]) -> Vec<(usize, VmCommand)> {
+    let mut reachable = true;
+
+    commands.iter()
+        .filter(|&(_, command)| {
+            match *command {
+                VmCommand::Label(_) | VmCommand::Function(..) => reachable = true,
+                _ if !reachable => return false,
+                VmCommand::Goto(_) | VmCommand::Return => reachable = false,
+                _ => (),
+            }
+            true
+        })
+        .cloned()
+        .collect()
+}
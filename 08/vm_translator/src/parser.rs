@@ -0,0 +1,54 @@
+use vm_command::VmCommand;
+
+pub struct Parser<'a> {
+    remaining: &'a str,
+    current_line: &'a str,
+    line_number: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(buffer: &'a str) -> Self {
+        Parser {
+            remaining: buffer,
+            current_line: "",
+            line_number: 0,
+        }
+    }
+
+    pub fn has_more_commands(&self) -> bool {
+        self.remaining.lines().any(|line| {
+            let line = line.trim();
+            !(line.is_empty() || line.starts_with("//"))
+        })
+    }
+
+    pub fn advance(&mut self) {
+        self.current_line = "";
+
+        while self.current_line.is_empty() || self.current_line.starts_with("//") {
+            let mut lines = self.remaining.splitn(2, '\n');
+
+            self.current_line = lines.next().unwrap();
+            self.current_line = self.current_line.split("//").next().unwrap().trim();
+
+            self.remaining = lines.next().unwrap_or("");
+            self.line_number += 1;
+        }
+    }
+
+    /// 1-based line of the current command in the .vm file
+    pub fn line_number(&self) -> usize {
+        self.line_number
+    }
+
+    /// The current command, or a description of what is wrong with it
+    pub fn command(&self) -> Result<VmCommand, String> {
+        self.current_line.parse()
+    }
+
+    pub fn print(&self) {
+        for line in self.remaining.lines() {
+            println!("{}", line);
+        }
+    }
+}
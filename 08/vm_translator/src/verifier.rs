@@ -0,0 +1,167 @@
+//! Checks a whole VM program for mistakes the translator would otherwise
+//! turn into broken assembly without complaint.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use Source;
+use vm_command::{Segment, VmCommand};
+
+// The commands of one function, or those before the first function of a
+// file, which is the scope of their labels
+struct Scope<'a> {
+    file: &'a str,
+    name: Option<&'a str>,
+    commands: &'a [(usize, VmCommand)],
+}
+
+/// Reports calls to undefined functions or with too few arguments, jumps
+/// to labels their function does not define, and stack depths that differ
+/// between the paths reaching a label or `return`, each as
+/// `file:line: message`. Segment misuse is already refused by the parser.
+pub fn verify(sources: &[Source], files: &[Vec<(usize, VmCommand)>]) -> Vec<String> {
+    let mut scopes = Vec::new();
+    for (source, commands) in sources.iter().zip(files) {
+        let file = Path::new(&source.name).file_name().unwrap().to_str().unwrap();
+        let mut start = 0;
+        let mut name = None;
+        for (i, (_, command)) in commands.iter().enumerate() {
+            if let VmCommand::Function(ref function, _) = *command {
+                if i > start || name.is_some() {
+                    scopes.push(Scope { file, name, commands: &commands[start..i] });
+                }
+                start = i;
+                name = Some(function.as_str());
+            }
+        }
+        if start < commands.len() || name.is_some() {
+            scopes.push(Scope { file, name, commands: &commands[start..] });
+        }
+    }
+
+    // Arguments each function needs, from the highest index it uses
+    let mut arguments = BTreeMap::new();
+    for scope in &scopes {
+        if let Some(name) = scope.name {
+            let needed = scope.commands
+                .iter()
+                .filter_map(|(_, command)| match *command {
+                    VmCommand::Push(Segment::Argument, index) | VmCommand::Pop(Segment::Argument, index) => Some(index + 1),
+                    _ => None,
+                })
+                .max()
+                .unwrap_or(0);
+            arguments.insert(name, needed);
+        }
+    }
+
+    let mut errors = Vec::new();
+    for scope in &scopes {
+        let mut report = |line: usize, message: String| errors.push(format!("{}:{}: {}", scope.file, line, message));
+
+        let labels: BTreeSet<&str> = scope.commands
+            .iter()
+            .filter_map(|(_, command)| match *command {
+                VmCommand::Label(ref label) => Some(label.as_str()),
+                _ => None,
+            })
+            .collect();
+
+        for &(line, ref command) in scope.commands {
+            match *command {
+                VmCommand::Goto(ref label) | VmCommand::IfGoto(ref label) if !labels.contains(label.as_str()) => {
+                    report(line, format!("Label '{}' is not defined in {}", label, scope.name.unwrap_or("this file")));
+                }
+                VmCommand::Call(ref function, args) => {
+                    match arguments.get(function.as_str()) {
+                        None => report(line, format!("Call to undefined function '{}'", function)),
+                        Some(&needed) if args < needed => {
+                            report(line, format!("'{}' uses {} arguments, but is called with {}", function, needed, args));
+                        }
+                        Some(_) => (),
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        check_stack_depth(scope, &mut report);
+    }
+    errors
+}
+
+// Follows every path through the scope, checking that the stack never
+// drops below where the scope started, and that each label and `return`
+// is always reached with the same number of values on the stack
+fn check_stack_depth<F: FnMut(usize, String)>(scope: &Scope, report: &mut F) {
+    let commands = scope.commands;
+    let mut targets = BTreeMap::new();
+    for (i, (_, command)) in commands.iter().enumerate() {
+        if let VmCommand::Label(ref label) = *command {
+            targets.insert(label.as_str(), i);
+        }
+    }
+
+    // Depth on reaching each command, and at the first `return` reached
+    let mut depths: Vec<Option<i32>> = vec![None; commands.len()];
+    let mut return_depth = None;
+    let mut pending = vec![(0, 0)];
+
+    while let Some((i, depth)) = pending.pop() {
+        if i >= commands.len() {
+            continue;
+        }
+        let (line, ref command) = commands[i];
+
+        match depths[i] {
+            Some(known) if known != depth => {
+                if let VmCommand::Label(ref label) = *command {
+                    report(line, format!("Stack depth at label '{}' is {} on one path and {} on another",
+                                         label, known, depth));
+                }
+                continue;
+            }
+            Some(_) => continue,
+            None => depths[i] = Some(depth),
+        }
+
+        let after = depth + match *command {
+            VmCommand::Arithmetic(op) => 1 - op.arity() as i32,
+            VmCommand::Push(..) => 1,
+            VmCommand::Pop(..) | VmCommand::IfGoto(_) => -1,
+            VmCommand::Call(_, args) => 1 - args as i32,
+            _ => 0,
+        };
+        if after < 0 {
+            report(line, format!("'{}' pops more values than are on the stack", command));
+            continue;
+        }
+
+        match *command {
+            VmCommand::Return => {
+                if depth == 0 {
+                    report(line, "'return' with no value on the stack".to_string());
+                }
+                match return_depth {
+                    Some(known) if known != depth => {
+                        report(line, format!("Stack depth at 'return' is {}, but {} at another 'return'", depth, known));
+                    }
+                    Some(_) => (),
+                    None => return_depth = Some(depth),
+                }
+            }
+            VmCommand::Goto(ref label) => {
+                if let Some(&target) = targets.get(label.as_str()) {
+                    pending.push((target, after));
+                }
+            }
+            VmCommand::IfGoto(ref label) => {
+                if let Some(&target) = targets.get(label.as_str()) {
+                    pending.push((target, after));
+                }
+                pending.push((i + 1, after));
+            }
+            _ => pending.push((i + 1, after)),
+        }
+    }
+}
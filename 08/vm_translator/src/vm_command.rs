@@ -0,0 +1,234 @@
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Segment {
+    Argument,
+    Local,
+    Static,
+    Constant,
+    This,
+    That,
+    Pointer,
+    Temp,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ArithOp {
+    Add,
+    Sub,
+    Neg,
+    Eq,
+    Gt,
+    Lt,
+    And,
+    Or,
+    Not,
+    // Not part of the standard VM language. `div` truncates towards zero
+    // and gives 0 for a divisor of 0 unless translated with --checked, and
+    // `shr` shifts in zeros; shifting by 16 or more gives 0.
+    Mul,
+    Div,
+    Shl,
+    Shr,
+}
+
+/// One VM command. Parsed from text with `str::parse`, which checks
+/// everything the translator relies on, and printed back in canonical form
+/// with `Display`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VmCommand {
+    Arithmetic(ArithOp),
+    Push(Segment, u16),
+    Pop(Segment, u16),
+    Label(String),
+    Goto(String),
+    IfGoto(String),
+    Function(String, u16),
+    Call(String, u16),
+    Return,
+}
+
+impl Segment {
+    /// Highest index the segment can be addressed with
+    pub fn limit(self) -> u16 {
+        match self {
+            Segment::Pointer => 1,
+            Segment::Temp => 7,
+            _ => 32767,
+        }
+    }
+}
+
+impl ArithOp {
+    /// Number of stack values the operation consumes
+    pub fn arity(self) -> u16 {
+        match self {
+            ArithOp::Neg | ArithOp::Not => 1,
+            _ => 2,
+        }
+    }
+}
+
+impl FromStr for Segment {
+    type Err = String;
+
+    fn from_str(segment: &str) -> Result<Self, Self::Err> {
+        match segment.to_lowercase().as_ref() {
+            "argument" => Ok(Segment::Argument),
+            "local" => Ok(Segment::Local),
+            "static" => Ok(Segment::Static),
+            "constant" => Ok(Segment::Constant),
+            "this" => Ok(Segment::This),
+            "that" => Ok(Segment::That),
+            "pointer" => Ok(Segment::Pointer),
+            "temp" => Ok(Segment::Temp),
+            _ => Err(format!("Unknown segment '{}'", segment)),
+        }
+    }
+}
+
+impl FromStr for ArithOp {
+    type Err = String;
+
+    fn from_str(command: &str) -> Result<Self, Self::Err> {
+        match command.to_lowercase().as_ref() {
+            "add" => Ok(ArithOp::Add),
+            "sub" => Ok(ArithOp::Sub),
+            "neg" => Ok(ArithOp::Neg),
+            "eq" => Ok(ArithOp::Eq),
+            "gt" => Ok(ArithOp::Gt),
+            "lt" => Ok(ArithOp::Lt),
+            "and" => Ok(ArithOp::And),
+            "or" => Ok(ArithOp::Or),
+            "not" => Ok(ArithOp::Not),
+            "mul" => Ok(ArithOp::Mul),
+            "div" => Ok(ArithOp::Div),
+            "shl" => Ok(ArithOp::Shl),
+            "shr" => Ok(ArithOp::Shr),
+            _ => Err(format!("Unknown command '{}'", command)),
+        }
+    }
+}
+
+impl FromStr for VmCommand {
+    type Err = String;
+
+    /// Parses a single command with its comment already removed. Tokens
+    /// may be separated by any run of whitespace.
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        let keyword = *tokens.first().ok_or_else(|| "Empty command".to_string())?;
+
+        let arguments = match keyword {
+            "push" | "pop" | "function" | "call" => 2,
+            "label" | "goto" | "if-goto" => 1,
+            "return" => 0,
+            _ => keyword.parse::<ArithOp>().map(|_| 0)?,
+        };
+        if tokens.len() != arguments + 1 {
+            return Err(format!("'{}' expects {} argument{}, found {}",
+                               keyword,
+                               arguments,
+                               if arguments == 1 { "" } else { "s" },
+                               tokens.len() - 1));
+        }
+
+        match keyword {
+            "push" | "pop" => {
+                let segment: Segment = tokens[1].parse()?;
+                let index = parse_number(tokens[2])?;
+
+                if keyword == "pop" && segment == Segment::Constant {
+                    return Err("Cannot pop to the constant segment".to_string());
+                }
+                if index > segment.limit() {
+                    return Err(format!("Index {} is out of range for {} (0-{})",
+                                       index,
+                                       segment,
+                                       segment.limit()));
+                }
+
+                if keyword == "push" {
+                    Ok(VmCommand::Push(segment, index))
+                } else {
+                    Ok(VmCommand::Pop(segment, index))
+                }
+            }
+            "label" => Ok(VmCommand::Label(parse_symbol(tokens[1])?)),
+            "goto" => Ok(VmCommand::Goto(parse_symbol(tokens[1])?)),
+            "if-goto" => Ok(VmCommand::IfGoto(parse_symbol(tokens[1])?)),
+            "function" => Ok(VmCommand::Function(parse_symbol(tokens[1])?, parse_number(tokens[2])?)),
+            "call" => Ok(VmCommand::Call(parse_symbol(tokens[1])?, parse_number(tokens[2])?)),
+            "return" => Ok(VmCommand::Return),
+            _ => keyword.parse().map(VmCommand::Arithmetic),
+        }
+    }
+}
+
+impl fmt::Display for Segment {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            Segment::Argument => "argument",
+            Segment::Local => "local",
+            Segment::Static => "static",
+            Segment::Constant => "constant",
+            Segment::This => "this",
+            Segment::That => "that",
+            Segment::Pointer => "pointer",
+            Segment::Temp => "temp",
+        })
+    }
+}
+
+impl fmt::Display for ArithOp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            ArithOp::Add => "add",
+            ArithOp::Sub => "sub",
+            ArithOp::Neg => "neg",
+            ArithOp::Eq => "eq",
+            ArithOp::Gt => "gt",
+            ArithOp::Lt => "lt",
+            ArithOp::And => "and",
+            ArithOp::Or => "or",
+            ArithOp::Not => "not",
+            ArithOp::Mul => "mul",
+            ArithOp::Div => "div",
+            ArithOp::Shl => "shl",
+            ArithOp::Shr => "shr",
+        })
+    }
+}
+
+impl fmt::Display for VmCommand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            VmCommand::Arithmetic(op) => write!(f, "{}", op),
+            VmCommand::Push(segment, index) => write!(f, "push {} {}", segment, index),
+            VmCommand::Pop(segment, index) => write!(f, "pop {} {}", segment, index),
+            VmCommand::Label(ref label) => write!(f, "label {}", label),
+            VmCommand::Goto(ref label) => write!(f, "goto {}", label),
+            VmCommand::IfGoto(ref label) => write!(f, "if-goto {}", label),
+            VmCommand::Function(ref name, locals) => write!(f, "function {} {}", name, locals),
+            VmCommand::Call(ref name, args) => write!(f, "call {} {}", name, args),
+            VmCommand::Return => write!(f, "return"),
+        }
+    }
+}
+
+fn parse_number(token: &str) -> Result<u16, String> {
+    token.parse::<u16>().map_err(|_| format!("Expected a number, found '{}'", token))
+}
+
+// Labels and function names follow the rules for Hack assembly symbols
+fn parse_symbol(symbol: &str) -> Result<String, String> {
+    let valid = !symbol.starts_with(|c: char| c.is_ascii_digit()) &&
+                symbol.chars().all(|c| c.is_ascii_alphanumeric() || "_.$:".contains(c));
+
+    if valid {
+        Ok(symbol.to_string())
+    } else {
+        Err(format!("Invalid symbol '{}'", symbol))
+    }
+}
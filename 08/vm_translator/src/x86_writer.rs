@@ -0,0 +1,405 @@
+use std::collections::BTreeMap;
+use std::io;
+use std::io::prelude::*;
+use std::mem;
+
+use vm_command::{ArithOp, Segment, VmCommand};
+
+// First RAM address the assembler gives a variable
+const STATIC_BASE: u16 = 16;
+
+/// Writes a whole VM program as x86-64 assembly for the GNU assembler on
+/// Linux, to be linked with `c_writer::RUNTIME`:
+///
+/// ```text
+/// cc -o Prog Prog.s Prog.runtime.c
+/// ```
+///
+/// Like `CWriter`, the code keeps all of its data in the runtime's `ram`
+/// array where the Hack translation puts it, saving and restoring frames
+/// in RAM as `write_call` and `write_return` do for Hack, and it halts and
+/// reports the same way. Each VM function becomes a native one entered
+/// with `call`, so return addresses go on the native stack and the one
+/// saved in RAM is only a command index, as in `vm_emulator`.
+///
+/// `%rbx` holds the address of `ram` throughout. RAM addresses are worked
+/// out in 16-bit registers, so they wrap as on the Hack.
+pub struct X86Writer<W: Write> {
+    out_file: W,
+    file_name: String,
+    // Index of every function defined in the program, which names its
+    // symbol
+    functions: BTreeMap<String, usize>,
+    // Index of the function being written, or None before the first one
+    function: Option<usize>,
+    bootstrap: bool,
+    // Number of each label of the current function, and whether it has
+    // been defined
+    labels: BTreeMap<String, (usize, bool)>,
+    last_label: Option<String>,
+    statics: BTreeMap<String, u16>,
+    // Commands written so far. A call saves the index of the command
+    // after it as its return address, as `vm_emulator` does.
+    commands: u16,
+    // Messages `vm_fail` is called with, written out by `close`
+    messages: Vec<String>,
+}
+
+impl<W: Write> X86Writer<W> {
+    pub fn new(out_file: W) -> Self {
+        X86Writer {
+            out_file,
+            file_name: "".to_string(),
+            functions: BTreeMap::new(),
+            function: None,
+            bootstrap: false,
+            labels: BTreeMap::new(),
+            last_label: None,
+            statics: BTreeMap::new(),
+            commands: 0,
+            messages: Vec::new(),
+        }
+    }
+
+    /// Gives back the underlying writer
+    pub fn into_inner(self) -> W {
+        self.out_file
+    }
+
+    /// Notes every function, which must be all those the program defines,
+    /// and starts `vm_start`: with `bootstrap` it calls `Sys.init`,
+    /// otherwise it runs the commands that come before the first
+    /// `function`.
+    pub fn write_init(&mut self, functions: &[String], bootstrap: bool) -> io::Result<()> {
+        for (index, function) in functions.iter().enumerate() {
+            self.functions.insert(function.clone(), index);
+        }
+        self.bootstrap = bootstrap;
+
+        self.out_file.write_all(b"# Translated from VM code by vm_translator\n\
+                                  \t.text\n\
+                                  \t.globl vm_start\n\
+                                  vm_start:\n\
+                                  \tpushq %rbx\n\
+                                  \tleaq ram(%rip), %rbx\n")?;
+        if bootstrap {
+            self.write_call("Sys.init", 0)?;
+            self.write_halt()?;
+        }
+        Ok(())
+    }
+
+    /// Writes `text` as a comment line of its own
+    pub fn write_comment(&mut self, text: &str) -> io::Result<()> {
+        writeln!(self.out_file, "\t# {}", text)
+    }
+
+    pub fn set_file_name(&mut self, name: &str) {
+        self.file_name = name.to_string();
+    }
+
+    pub fn write_command(&mut self, command: &VmCommand) -> io::Result<()> {
+        let last_label = self.last_label.take();
+        self.commands = self.commands.wrapping_add(1);
+        match *command {
+            VmCommand::Arithmetic(op) => self.write_arithmetic(op),
+            VmCommand::Push(segment, index) => self.write_push(segment, index),
+            VmCommand::Pop(segment, index) => self.write_pop(segment, index),
+            VmCommand::Label(ref label) => {
+                self.last_label = Some(label.clone());
+                self.write_label(label)
+            }
+            // A jump to the label just before it is how programs halt
+            VmCommand::Goto(ref label) if last_label.as_ref() == Some(label) => self.write_halt(),
+            VmCommand::Goto(ref label) => self.write_goto(label),
+            VmCommand::IfGoto(ref label) => self.write_if(label),
+            VmCommand::Function(ref name, num_locals) => self.write_function(name, num_locals),
+            VmCommand::Return => self.write_return(),
+            VmCommand::Call(ref name, num_args) => self.write_call(name, num_args),
+        }
+    }
+
+    pub fn write_arithmetic(&mut self, op: ArithOp) -> io::Result<()> {
+        // Unary operations work on the top of the stack in place
+        match op {
+            ArithOp::Neg | ArithOp::Not => {
+                let instruction = if op == ArithOp::Neg { "negw" } else { "notw" };
+                return writeln!(self.out_file,
+                                "\tmovzwl (%rbx), %eax\n\
+                                 \tdecw %ax\n\
+                                 \t{} (%rbx,%rax,2)",
+                                instruction);
+            }
+            _ => (),
+        }
+
+        // Pops y into %cx and leaves the address of x in %rax
+        self.out_file.write_all(b"\tdecw (%rbx)\n\
+                                  \tmovzwl (%rbx), %eax\n\
+                                  \tmovw (%rbx,%rax,2), %cx\n\
+                                  \tdecw %ax\n")?;
+
+        match op {
+            ArithOp::Add => self.out_file.write_all(b"\taddw %cx, (%rbx,%rax,2)\n"),
+            ArithOp::Sub => self.out_file.write_all(b"\tsubw %cx, (%rbx,%rax,2)\n"),
+            ArithOp::And => self.out_file.write_all(b"\tandw %cx, (%rbx,%rax,2)\n"),
+            ArithOp::Or => self.out_file.write_all(b"\torw %cx, (%rbx,%rax,2)\n"),
+            ArithOp::Eq | ArithOp::Gt | ArithOp::Lt => {
+                let condition = match op {
+                    ArithOp::Eq => "e",
+                    ArithOp::Gt => "g",
+                    _ => "l",
+                };
+                writeln!(self.out_file,
+                         "\tcmpw %cx, (%rbx,%rax,2)\n\
+                          \tset{} %dl\n\
+                          \tmovzbw %dl, %dx\n\
+                          \tnegw %dx\n\
+                          \tmovw %dx, (%rbx,%rax,2)",
+                         condition)
+            }
+            ArithOp::Mul => {
+                self.out_file.write_all(b"\tmovw (%rbx,%rax,2), %dx\n\
+                                          \timulw %cx, %dx\n\
+                                          \tmovw %dx, (%rbx,%rax,2)\n")
+            }
+            // Dividing in 32 bits, so -32768 / -1 wraps rather than traps,
+            // and by zero gives 0
+            ArithOp::Div => {
+                self.out_file.write_all(b"\tmovl %eax, %esi\n\
+                                          \tmovswl %cx, %ecx\n\
+                                          \txorl %eax, %eax\n\
+                                          \ttestl %ecx, %ecx\n\
+                                          \tjz 1f\n\
+                                          \tmovswl (%rbx,%rsi,2), %eax\n\
+                                          \tcltd\n\
+                                          \tidivl %ecx\n\
+                                          1:\n\
+                                          \tmovw %ax, (%rbx,%rsi,2)\n")
+            }
+            // Shifting by 16 or more gives 0
+            ArithOp::Shl | ArithOp::Shr => {
+                let instruction = if op == ArithOp::Shl { "shll" } else { "shrl" };
+                writeln!(self.out_file,
+                         "\txorl %edx, %edx\n\
+                          \tcmpw $16, %cx\n\
+                          \tjae 1f\n\
+                          \tmovzwl (%rbx,%rax,2), %edx\n\
+                          \t{} %cl, %edx\n\
+                          1:\n\
+                          \tmovw %dx, (%rbx,%rax,2)",
+                         instruction)
+            }
+            ArithOp::Neg | ArithOp::Not => unreachable!(),
+        }
+    }
+
+    pub fn write_push(&mut self, segment: Segment, index: u16) -> io::Result<()> {
+        match segment {
+            Segment::Constant => writeln!(self.out_file, "\tmovw ${}, %cx", index)?,
+            _ => {
+                let address = self.address(segment, index);
+                writeln!(self.out_file, "{}\tmovw {}, %cx", address.0, address.1)?;
+            }
+        }
+        self.out_file.write_all(b"\tmovzwl (%rbx), %eax\n\
+                                  \tmovw %cx, (%rbx,%rax,2)\n\
+                                  \tincw (%rbx)\n")
+    }
+
+    pub fn write_pop(&mut self, segment: Segment, index: u16) -> io::Result<()> {
+        self.out_file.write_all(b"\tdecw (%rbx)\n\
+                                  \tmovzwl (%rbx), %eax\n\
+                                  \tmovw (%rbx,%rax,2), %cx\n")?;
+        let address = self.address(segment, index);
+        writeln!(self.out_file, "{}\tmovw %cx, {}", address.0, address.1)
+    }
+
+    // Code that puts the address of `segment index` in %rax if needed, and
+    // the operand for the word there
+    fn address(&mut self, segment: Segment, index: u16) -> (String, String) {
+        let pointer = match segment {
+            Segment::Local => 1,
+            Segment::Argument => 2,
+            Segment::This => 3,
+            Segment::That => 4,
+            Segment::Pointer => return (String::new(), format!("{}(%rbx)", 2 * (3 + index))),
+            Segment::Temp => return (String::new(), format!("{}(%rbx)", 2 * (5 + index))),
+            // The assembler numbers variables in order of first use
+            Segment::Static => {
+                let next = STATIC_BASE + self.statics.len() as u16;
+                let name = format!("{}.{}", self.file_name, index);
+                let address = *self.statics.entry(name).or_insert(next);
+                return (String::new(), format!("{}(%rbx)", 2 * address as u32));
+            }
+            Segment::Constant => unreachable!(),
+        };
+
+        let mut code = format!("\tmovzwl {}(%rbx), %eax\n", 2 * pointer);
+        if index > 0 {
+            code += &format!("\taddw ${}, %ax\n", index);
+        }
+        (code, "(%rbx,%rax,2)".to_string())
+    }
+
+    // Assembly label of `label` in the current function
+    fn label_name(&mut self, label: &str) -> String {
+        let next = self.labels.len();
+        let number = self.labels.entry(label.to_string()).or_insert((next, false)).0;
+        self.label_symbol(number)
+    }
+
+    fn label_symbol(&self, number: usize) -> String {
+        match self.function {
+            Some(function) => format!(".L{}_{}", function, number),
+            None => format!(".Lstart_{}", number),
+        }
+    }
+
+    pub fn write_label(&mut self, label: &str) -> io::Result<()> {
+        let name = self.label_name(label);
+        self.labels.get_mut(label).unwrap().1 = true;
+        writeln!(self.out_file, "{}:", name)
+    }
+
+    pub fn write_goto(&mut self, label: &str) -> io::Result<()> {
+        let name = self.label_name(label);
+        writeln!(self.out_file, "\tjmp {}", name)
+    }
+
+    pub fn write_if(&mut self, label: &str) -> io::Result<()> {
+        let name = self.label_name(label);
+        writeln!(self.out_file,
+                 "\tdecw (%rbx)\n\
+                  \tmovzwl (%rbx), %eax\n\
+                  \tcmpw $0, (%rbx,%rax,2)\n\
+                  \tjne {}",
+                 name)
+    }
+
+    pub fn write_function(&mut self, function_name: &str, num_locals: u16) -> io::Result<()> {
+        let index = self.functions[function_name];
+        // Without the bootstrap code the commands before the first
+        // function run on into it
+        if self.function.is_none() && !self.bootstrap {
+            writeln!(self.out_file, "\tcall vm_f{}", index)?;
+        }
+        self.end_function()?;
+        self.function = Some(index);
+
+        writeln!(self.out_file, "\n# {}\nvm_f{}:", function_name, index)?;
+        if function_name == "Sys.halt" {
+            self.write_halt()?;
+        }
+        if num_locals > 0 {
+            writeln!(self.out_file,
+                     "\tmovzwl (%rbx), %eax\n\
+                      \tmovl ${}, %ecx\n\
+                      1:\n\
+                      \tmovw $0, (%rbx,%rax,2)\n\
+                      \tincw %ax\n\
+                      \tdecl %ecx\n\
+                      \tjnz 1b\n\
+                      \tmovw %ax, (%rbx)",
+                     num_locals)?;
+        }
+        Ok(())
+    }
+
+    // Ends the function being written. Jumps to labels it never defined
+    // fail at run time.
+    fn end_function(&mut self) -> io::Result<()> {
+        self.write_halt()?;
+        let labels = mem::take(&mut self.labels);
+        for (label, (number, defined)) in labels {
+            if !defined {
+                writeln!(self.out_file, "{}:", self.label_symbol(number))?;
+                self.write_fail(&format!("Label {} is not defined", label))?;
+            }
+        }
+        Ok(())
+    }
+
+    // Calls `vm_halt`, which does not return, so the stack only needs
+    // aligning for it
+    fn write_halt(&mut self) -> io::Result<()> {
+        self.out_file.write_all(b"\tandq $-16, %rsp\n\
+                                  \tcall vm_halt\n")
+    }
+
+    fn write_fail(&mut self, message: &str) -> io::Result<()> {
+        writeln!(self.out_file,
+                 "\tleaq .Lmessage{}(%rip), %rdi\n\
+                  \tandq $-16, %rsp\n\
+                  \tcall vm_fail",
+                 self.messages.len())?;
+        self.messages.push(message.to_string());
+        Ok(())
+    }
+
+    pub fn write_return(&mut self) -> io::Result<()> {
+        if self.function.is_none() {
+            return self.write_fail("return outside any function");
+        }
+
+        // The frame starts at LCL, kept in %dx. The return value goes to
+        // where the arguments started, and SP just past it.
+        self.out_file.write_all(b"\tmovzwl 2(%rbx), %edx\n\
+                                  \tdecw (%rbx)\n\
+                                  \tmovzwl (%rbx), %eax\n\
+                                  \tmovw (%rbx,%rax,2), %cx\n\
+                                  \tmovzwl 4(%rbx), %eax\n\
+                                  \tmovw %cx, (%rbx,%rax,2)\n\
+                                  \tincw %ax\n\
+                                  \tmovw %ax, (%rbx)\n\
+                                  \tmovl %edx, %eax\n")?;
+
+        // THAT, THIS, ARG and LCL are saved below the frame
+        for pointer in &[4, 3, 2, 1] {
+            writeln!(self.out_file,
+                     "\tdecw %ax\n\
+                      \tmovw (%rbx,%rax,2), %cx\n\
+                      \tmovw %cx, {}(%rbx)",
+                     2 * pointer)?;
+        }
+        self.out_file.write_all(b"\tret\n")
+    }
+
+    pub fn write_call(&mut self, function_name: &str, num_args: u16) -> io::Result<()> {
+        let index = match self.functions.get(function_name) {
+            Some(&index) => index,
+            None => return self.write_fail(&format!("Call to undefined function {}", function_name)),
+        };
+
+        // The return address, then the caller's LCL, ARG, THIS and THAT
+        writeln!(self.out_file, "\tmovw ${}, %cx", self.commands)?;
+        for pointer in 0..5 {
+            if pointer > 0 {
+                writeln!(self.out_file, "\tmovw {}(%rbx), %cx", 2 * pointer)?;
+            }
+            self.out_file.write_all(b"\tmovzwl (%rbx), %eax\n\
+                                      \tmovw %cx, (%rbx,%rax,2)\n\
+                                      \tincw (%rbx)\n")?;
+        }
+
+        // ARG = SP - num_args - 5 and LCL = SP
+        writeln!(self.out_file,
+                 "\tmovw (%rbx), %ax\n\
+                  \tmovw %ax, 2(%rbx)\n\
+                  \tsubw ${}, %ax\n\
+                  \tmovw %ax, 4(%rbx)\n\
+                  \tcall vm_f{}",
+                 num_args.wrapping_add(5),
+                 index)
+    }
+
+    /// Ends the last function and writes the messages it may fail with
+    pub fn close(&mut self) -> io::Result<()> {
+        self.end_function()?;
+        self.out_file.write_all(b"\n\t.section .rodata\n")?;
+        for (index, message) in self.messages.iter().enumerate() {
+            writeln!(self.out_file, ".Lmessage{}:\n\t.asciz \"{}\"", index, message)?;
+        }
+        self.out_file.write_all(b"\t.section .note.GNU-stack,\"\",@progbits\n")
+    }
+}
@@ -10,8 +10,7 @@ use symbol_table::SymbolTable;
 use symbol_table::IdentifierKind;
 
 use vm_writer::VMWriter;
-use vm_writer::Segment;
-use vm_writer::Command;
+use vm_translator::vm_command::{ArithOp, Segment};
 
 pub struct CompilationEngine {
     tokenizer: JackTokenizer,
@@ -19,6 +18,9 @@ pub struct CompilationEngine {
     symbol_table: SymbolTable,
     class_name: String,
     loop_counter: u16,
+    source_name: String,
+    map_path: PathBuf,
+    native_math: bool,
 }
 
 impl CompilationEngine {
@@ -29,7 +31,8 @@ impl CompilationEngine {
 
         let mut out_path = in_path.clone();
         out_path.set_extension("vm");
-        let mut out_file = File::create(out_path).expect("Unable to create output file.");
+        let mut out_file = File::create(&out_path).expect("Unable to create output file.");
+        let map_path = out_path.with_extension("vm.map");
 
         CompilationEngine {
             tokenizer: JackTokenizer::new(buffer),
@@ -37,9 +40,34 @@ impl CompilationEngine {
             symbol_table: SymbolTable::new(),
             class_name: "".to_string(),
             loop_counter: 0,
+            source_name: in_path.file_name().unwrap().to_str().unwrap().to_string(),
+            map_path: map_path,
+            native_math: false,
         }
     }
 
+    /// Compiles `*` and `/` to the `mul` and `div` VM commands rather than
+    /// calls to `Math.multiply` and `Math.divide`, and multiplying by a
+    /// power of two to `shl`. The translated code then needs no Math class
+    /// for them.
+    pub fn set_native_math(&mut self, native_math: bool) {
+        self.native_math = native_math;
+    }
+
+    /// Writes Foo.vm.map relating each line of Foo.vm to a line of Foo.jack
+    pub fn write_source_map(&self) {
+        let mut map_file = File::create(&self.map_path).expect("Unable to create output file.");
+        for &(vm_line, jack_line) in self.vm_writer.source_map() {
+            writeln!(map_file, "{} {} {}", vm_line, self.source_name, jack_line)
+                .expect("Unable to write source map.");
+        }
+    }
+
+    fn mark_source_line(&mut self) {
+        let line = self.tokenizer.line_number();
+        self.vm_writer.mark_source_line(line);
+    }
+
     pub fn compile_class(&mut self) {
         // class
         self.tokenizer.advance();
@@ -100,6 +128,7 @@ impl CompilationEngine {
     fn compile_subroutine(&mut self) {
         self.symbol_table.start_subroutine();
         self.loop_counter = 0;
+        let declaration_line = self.tokenizer.line_number();
 
         // constructor | function | method
         let subroutineType = self.tokenizer.key_word();
@@ -136,19 +165,20 @@ impl CompilationEngine {
         }
 
         // Write function declaration
+        self.vm_writer.mark_source_line(declaration_line);
         self.vm_writer.write_function(subroutineName,
                                       self.symbol_table.var_count(IdentifierKind::VAR));
         // If constructor, malloc initial
         if subroutineType == KeyWord::CONSTRUCTOR {
             let numField = self.symbol_table.var_count(IdentifierKind::FIELD);
-            self.vm_writer.write_push(Segment::CONSTANT, numField);
+            self.vm_writer.write_push(Segment::Constant, numField);
             self.vm_writer.write_call("Memory.alloc".to_string(), 1);
-            self.vm_writer.write_pop(Segment::POINTER, 0);
+            self.vm_writer.write_pop(Segment::Pointer, 0);
         }
         // If method, move arg0 to this
         else if subroutineType == KeyWord::METHOD {
-            self.vm_writer.write_push(Segment::ARG, 0);
-            self.vm_writer.write_pop(Segment::POINTER, 0);
+            self.vm_writer.write_push(Segment::Argument, 0);
+            self.vm_writer.write_pop(Segment::Pointer, 0);
         }
 
         // statements
@@ -197,6 +227,7 @@ impl CompilationEngine {
 
     fn compile_statements(&mut self) {
         while self.tokenizer.token_type() == TokenType::KEYWORD {
+            self.mark_source_line();
             match self.tokenizer.key_word() {
                 KeyWord::LET => self.compile_let(),
                 KeyWord::IF => self.compile_if(),
@@ -226,7 +257,7 @@ impl CompilationEngine {
         self.tokenizer.advance();
 
         // throw away the returned value
-        self.vm_writer.write_pop(Segment::TEMP, 0);
+        self.vm_writer.write_pop(Segment::Temp, 0);
     }
 
     fn compile_let(&mut self) {
@@ -236,10 +267,10 @@ impl CompilationEngine {
         // varName
         let varName = self.tokenizer.identifier();
         let mut segment = match self.symbol_table.kind_of(&varName).expect(&format!("Record not found: {}", varName)) {
-            IdentifierKind::ARG => Segment::ARG,
-            IdentifierKind::FIELD => Segment::THIS,
-            IdentifierKind::STATIC => Segment::STATIC,
-            IdentifierKind::VAR => Segment::LOCAL,
+            IdentifierKind::ARG => Segment::Argument,
+            IdentifierKind::FIELD => Segment::This,
+            IdentifierKind::STATIC => Segment::Static,
+            IdentifierKind::VAR => Segment::Local,
         };
         let mut index = self.symbol_table.index_of(&varName).unwrap();
         self.tokenizer.advance();
@@ -254,7 +285,7 @@ impl CompilationEngine {
 
             // compute offset
             self.vm_writer.write_push(segment, index);
-            self.vm_writer.write_arithmetic(Command::ADD);
+            self.vm_writer.write_arithmetic(ArithOp::Add);
 
             // ]
             self.tokenizer.advance();
@@ -266,11 +297,11 @@ impl CompilationEngine {
             self.compile_expression();
 
             // move value to temp location
-            self.vm_writer.write_pop(Segment::TEMP, 0);
+            self.vm_writer.write_pop(Segment::Temp, 0);
             // retreive offset and store
-            self.vm_writer.write_pop(Segment::POINTER, 1);
-            self.vm_writer.write_push(Segment::TEMP, 0);
-            self.vm_writer.write_pop(Segment::THAT, 0);
+            self.vm_writer.write_pop(Segment::Pointer, 1);
+            self.vm_writer.write_push(Segment::Temp, 0);
+            self.vm_writer.write_pop(Segment::That, 0);
         } else {
             // =
             self.tokenizer.advance();
@@ -302,7 +333,7 @@ impl CompilationEngine {
         self.compile_expression();
 
         // negate and jump to continue if true
-        self.vm_writer.write_arithmetic(Command::NOT);
+        self.vm_writer.write_arithmetic(ArithOp::Not);
         self.vm_writer.write_if(format!("WHILE_CONTINUE{}", counter));
 
         // )
@@ -313,6 +344,7 @@ impl CompilationEngine {
 
         // statements
         self.compile_statements();
+        self.mark_source_line();
         self.vm_writer.write_goto(format!("WHILE_LOOP{}", counter));
 
         // }
@@ -328,7 +360,7 @@ impl CompilationEngine {
         if self.tokenizer.token_type() != TokenType::SYMBOL || self.tokenizer.symbol() != ';' {
             self.compile_expression();
         } else {
-            self.vm_writer.write_push(Segment::CONSTANT, 0);
+            self.vm_writer.write_push(Segment::Constant, 0);
         }
 
         self.vm_writer.write_return();
@@ -349,7 +381,7 @@ impl CompilationEngine {
         self.compile_expression();
 
         // negate and jump to else if true(the negation)
-        self.vm_writer.write_arithmetic(Command::NOT);
+        self.vm_writer.write_arithmetic(ArithOp::Not);
         self.vm_writer.write_if(format!("IF_ELSE{}", counter));
 
         // )
@@ -362,6 +394,7 @@ impl CompilationEngine {
         self.compile_statements();
 
         // }
+        self.mark_source_line();
         self.tokenizer.advance();
 
         // when first is done, navigate to continue
@@ -381,6 +414,7 @@ impl CompilationEngine {
             self.compile_statements();
 
             // }
+            self.mark_source_line();
             self.tokenizer.advance();
         }
 
@@ -404,40 +438,64 @@ impl CompilationEngine {
             let op = self.tokenizer.symbol();
             self.tokenizer.advance();
 
+            // x * 2^n is x shifted left by n
+            if let (true, '*', Some(shift)) = (self.native_math, op, self.power_of_two()) {
+                self.vm_writer.write_push(Segment::Constant, shift);
+                self.vm_writer.write_arithmetic(ArithOp::Shl);
+                self.tokenizer.advance();
+                continue;
+            }
+
             // term
             self.compile_term();
 
             // execute operation on terms
             match op {
-                '+' => self.vm_writer.write_arithmetic(Command::ADD),
-                '-' => self.vm_writer.write_arithmetic(Command::SUB),
+                '+' => self.vm_writer.write_arithmetic(ArithOp::Add),
+                '-' => self.vm_writer.write_arithmetic(ArithOp::Sub),
+                '*' if self.native_math => self.vm_writer.write_arithmetic(ArithOp::Mul),
+                '/' if self.native_math => self.vm_writer.write_arithmetic(ArithOp::Div),
                 '*' => self.vm_writer.write_call("Math.multiply".to_string(), 2),
                 '/' => self.vm_writer.write_call("Math.divide".to_string(), 2),
-                '&' => self.vm_writer.write_arithmetic(Command::AND),
-                '|' => self.vm_writer.write_arithmetic(Command::OR),
-                '<' => self.vm_writer.write_arithmetic(Command::LT),
-                '>' => self.vm_writer.write_arithmetic(Command::GT),
-                '=' => self.vm_writer.write_arithmetic(Command::EQ),
+                '&' => self.vm_writer.write_arithmetic(ArithOp::And),
+                '|' => self.vm_writer.write_arithmetic(ArithOp::Or),
+                '<' => self.vm_writer.write_arithmetic(ArithOp::Lt),
+                '>' => self.vm_writer.write_arithmetic(ArithOp::Gt),
+                '=' => self.vm_writer.write_arithmetic(ArithOp::Eq),
                 _ => panic!("Invalid binary operation."),
             }
         }
     }
 
+    // Exponent of the integer constant term at the current token, if it
+    // is 2 or more and a power of two
+    fn power_of_two(&self) -> Option<u16> {
+        if self.tokenizer.token_type() != TokenType::INT_CONST {
+            return None;
+        }
+        let value = self.tokenizer.int_val();
+        if value > 1 && value.is_power_of_two() {
+            Some(value.trailing_zeros() as u16)
+        } else {
+            None
+        }
+    }
+
     fn compile_term(&mut self) {
         match self.tokenizer.token_type() {
             // integerConstant | stringConstant | keywordConstant
             TokenType::INT_CONST => {
                 // push constant
-                self.vm_writer.write_push(Segment::CONSTANT, self.tokenizer.int_val());
+                self.vm_writer.write_push(Segment::Constant, self.tokenizer.int_val());
                 self.tokenizer.advance();
             },
             TokenType::STRING_CONST => {
                 let chars: Vec<u8> = self.tokenizer.string_val().bytes().collect();
-                self.vm_writer.write_push(Segment::CONSTANT, chars.len() as u16);
+                self.vm_writer.write_push(Segment::Constant, chars.len() as u16);
                 self.vm_writer.write_call("String.new".to_string(), 1);
 
                 for c in self.tokenizer.string_val().bytes() {
-                    self.vm_writer.write_push(Segment::CONSTANT, c as u16);
+                    self.vm_writer.write_push(Segment::Constant, c as u16);
                     self.vm_writer.write_call("String.appendChar".to_string(), 2);
                 }
             
@@ -447,13 +505,13 @@ impl CompilationEngine {
                 // push keyword
                 match self.tokenizer.key_word() {
                     KeyWord::TRUE => {
-                        self.vm_writer.write_push(Segment::CONSTANT, 0);
-                        self.vm_writer.write_arithmetic(Command::NOT)
+                        self.vm_writer.write_push(Segment::Constant, 0);
+                        self.vm_writer.write_arithmetic(ArithOp::Not)
                     }
                     KeyWord::FALSE | KeyWord::NULL => {
-                        self.vm_writer.write_push(Segment::CONSTANT, 0)
+                        self.vm_writer.write_push(Segment::Constant, 0)
                     }
-                    KeyWord::THIS => self.vm_writer.write_push(Segment::POINTER, 0),
+                    KeyWord::THIS => self.vm_writer.write_push(Segment::Pointer, 0),
                     _ => panic!("Invalid keyword term: {}", self.tokenizer.identifier()),
                 }
                 self.tokenizer.advance();
@@ -467,8 +525,8 @@ impl CompilationEngine {
                     self.compile_term();
 
                     match op {
-                        '-' => self.vm_writer.write_arithmetic(Command::NEG),
-                        '~' => self.vm_writer.write_arithmetic(Command::NOT),
+                        '-' => self.vm_writer.write_arithmetic(ArithOp::Neg),
+                        '~' => self.vm_writer.write_arithmetic(ArithOp::Not),
                         _ => panic!("Invalid unary op"),
                     }
                 }
@@ -500,10 +558,10 @@ impl CompilationEngine {
                     else {
                         let segment =
                             match self.symbol_table.kind_of(&name).expect(&format!("Record not found: {}", name)) {
-                                IdentifierKind::ARG => Segment::ARG,
-                                IdentifierKind::FIELD => Segment::THIS,
-                                IdentifierKind::STATIC => Segment::STATIC,
-                                IdentifierKind::VAR => Segment::LOCAL,
+                                IdentifierKind::ARG => Segment::Argument,
+                                IdentifierKind::FIELD => Segment::This,
+                                IdentifierKind::STATIC => Segment::Static,
+                                IdentifierKind::VAR => Segment::Local,
                             };
                         let index = self.symbol_table.index_of(&name).unwrap();
                         // [expression]
@@ -514,9 +572,9 @@ impl CompilationEngine {
                         // expression
                         self.compile_expression();
                         self.vm_writer.write_push(segment,index);
-                        self.vm_writer.write_arithmetic(Command::ADD);
-                        self.vm_writer.write_pop(Segment::POINTER, 1);
-                        self.vm_writer.write_push(Segment::THAT, 0);
+                        self.vm_writer.write_arithmetic(ArithOp::Add);
+                        self.vm_writer.write_pop(Segment::Pointer, 1);
+                        self.vm_writer.write_push(Segment::That, 0);
 
                         // ]
                         self.tokenizer.advance();
@@ -567,10 +625,10 @@ impl CompilationEngine {
                 // instance method
                 Some(varType) => {
                     let segment = match self.symbol_table.kind_of(&so_far).unwrap() {
-                        IdentifierKind::ARG => Segment::ARG,
-                        IdentifierKind::FIELD => Segment::THIS,
-                        IdentifierKind::STATIC => Segment::STATIC,
-                        IdentifierKind::VAR => Segment::LOCAL,
+                        IdentifierKind::ARG => Segment::Argument,
+                        IdentifierKind::FIELD => Segment::This,
+                        IdentifierKind::STATIC => Segment::Static,
+                        IdentifierKind::VAR => Segment::Local,
                     };
                     let index = self.symbol_table.index_of(&so_far).unwrap();
 
@@ -588,7 +646,7 @@ impl CompilationEngine {
         } else {
             // function on current instance
             subroutineName = self.class_name.clone() + "." + &so_far;
-            self.vm_writer.write_push(Segment::POINTER, 0);
+            self.vm_writer.write_push(Segment::Pointer, 0);
             numArgs = 1;
         }
 
@@ -2,7 +2,7 @@ use std::fs;
 use std::fs::File;
 use std::path::PathBuf;
 
-use regex::Regex;
+use regex::{Captures, Regex};
 
 #[derive(Debug, PartialEq)]
 pub enum TokenType {
@@ -40,6 +40,7 @@ pub enum KeyWord {
 
 pub struct JackTokenizer {
     tokens: Vec<String>,
+    lines: Vec<usize>,
     index: usize,
     rexpr_keyword: Regex,
     rexpr_symbol: Regex,
@@ -57,7 +58,7 @@ static RE_IDENTIFIER: &'static str = r"([:word:]+)";
 impl JackTokenizer {
     pub fn new(buffer: String) -> JackTokenizer {
         let buffer = JackTokenizer::strip(buffer);
-        let tokens = JackTokenizer::extract_tokens(buffer);
+        let (tokens, lines) = JackTokenizer::extract_tokens(buffer);
         let keyword_specific = "^(".to_string() + RE_KEYWORD + &")$".to_string();
         let symbol_specific = "^(".to_string() + RE_SYMBOL + &")$".to_string();
         let integer_specific = "^(".to_string() + RE_INTEGER + &")$".to_string();
@@ -65,6 +66,7 @@ impl JackTokenizer {
         let identifier_specific = "^(".to_string() + RE_IDENTIFIER + &")$".to_string();
         JackTokenizer {
             tokens: tokens,
+            lines: lines,
             index: 0,
             rexpr_keyword: Regex::new(&keyword_specific).unwrap(),
             rexpr_symbol: Regex::new(&symbol_specific).unwrap(),
@@ -144,34 +146,49 @@ impl JackTokenizer {
         token.parse::<u16>().expect("Unable to parase token.")
     }
 
+    /// 1-based line of the current token in the .jack file
+    pub fn line_number(&self) -> usize {
+        self.lines[self.index]
+    }
+
     pub fn string_val(&self) -> String {
         let ref token = self.tokens[self.index];
 
         token.trim_matches('"').to_string()
     }
 
+    // Newlines are kept so tokens can still be traced back to their lines
     fn strip(buffer: String) -> String {
         // Single-line comments
-        let re = Regex::new(r"//[^\n]*\n").unwrap();
+        let re = Regex::new(r"//[^\n]*").unwrap();
         let buffer = re.replace_all(&buffer, "");
 
         // Multi-line comments
         let re = Regex::new(r"(?ms)/\*.*?\*/").unwrap();
-        let buffer = re.replace_all(&buffer, "");
+        let buffer = re.replace_all(&buffer, |cap: &Captures| {
+            cap.at(0).unwrap().chars().filter(|&c| c == '\n').collect::<String>()
+        });
 
         buffer
     }
 
-    fn extract_tokens(buffer: String) -> Vec<String> {
+    fn extract_tokens(buffer: String) -> (Vec<String>, Vec<usize>) {
         let rExpr = format!(r"{}|{}|{}|{}",
                             RE_SYMBOL,
                             RE_INTEGER,
                             RE_STRING,
                             RE_IDENTIFIER);
         let re = Regex::new(&rExpr).unwrap();
+        let mut line = 1;
+        let mut scanned = 0;
         re.captures_iter(&buffer)
-            .map(|cap| cap.at(0).unwrap().to_string())
-            .collect()
+            .map(|cap| {
+                let (start, _) = cap.pos(0).unwrap();
+                line += buffer[scanned..start].matches('\n').count();
+                scanned = start;
+                (cap.at(0).unwrap().to_string(), line)
+            })
+            .unzip()
     }
 
     pub fn rewind(&mut self) {
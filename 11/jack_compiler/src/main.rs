@@ -1,8 +1,12 @@
 use std::env;
-use std::fs;
 use std::path::{Path, PathBuf};
 
+extern crate getopts;
+use getopts::Options;
+use getopts::ParsingStyle;
+
 extern crate regex;
+extern crate vm_translator;
 
 mod jack_tokenizer;
 mod compilation_engine;
@@ -12,10 +16,23 @@ mod vm_writer;
 use compilation_engine::CompilationEngine;
 
 fn main() {
-    let mut args = env::args();
-    let program = args.next().unwrap();
-    let in_path = args.next().expect(&format!("Usage: {} PATH", program));
-    let in_path = Path::new(&in_path).to_path_buf();
+    let args: Vec<String> = env::args().collect();
+    let program = args[0].clone();
+
+    let mut opts = Options::new();
+    opts.optflag("m", "source-map", "write a .vm.map relating .vm lines to .jack lines");
+    opts.optflag("n", "native-math", "compile * and / to the mul, div and shl VM commands instead of Math calls");
+    opts.parsing_style(ParsingStyle::FloatingFrees);
+
+    let matches = opts.parse(&args[1..]).expect("Unable to parse arguments.");
+
+    let in_path = if matches.free.len() == 1 {
+        Path::new(&matches.free[0]).to_path_buf()
+    } else {
+        let brief = format!("Usage: {} PATH [options]", program);
+        println!("{}", opts.usage(&brief));
+        return;
+    };
 
     // Collect input files and create output folder
     let input_files: Vec<PathBuf> = if in_path.is_file() {
@@ -31,6 +48,11 @@ fn main() {
 
     for in_file in input_files {
         let mut engine = CompilationEngine::new(in_file);
+        engine.set_native_math(matches.opt_present("n"));
         engine.compile_class();
+
+        if matches.opt_present("m") {
+            engine.write_source_map();
+        }
     }
 }
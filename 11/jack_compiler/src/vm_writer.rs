@@ -1,98 +1,75 @@
 use std::fs::File;
 use std::io::Write;
 
-pub enum Segment {
-    ARG,
-    LOCAL,
-    STATIC,
-    THIS,
-    THAT,
-    POINTER,
-    TEMP,
-    CONSTANT,
-}
-
-pub enum Command {
-    ADD,
-    SUB,
-    NEG,
-    EQ,
-    GT,
-    LT,
-    AND,
-    OR,
-    NOT,
-}
+use vm_translator::vm_command::{ArithOp, Segment, VmCommand};
 
 pub struct VMWriter {
     out_file: File,
+    lines: usize,
+    // (.vm line, .jack line) where each run of commands starts
+    source_map: Vec<(usize, usize)>,
 }
 
 impl VMWriter {
     pub fn new(out_file: File) -> VMWriter {
-        VMWriter { out_file: out_file }
+        VMWriter {
+            out_file: out_file,
+            lines: 0,
+            source_map: Vec::new(),
+        }
+    }
+
+    /// Attributes the commands written from now on to a line of the .jack file
+    pub fn mark_source_line(&mut self, jack_line: usize) {
+        let vm_line = self.lines + 1;
+        if self.source_map.last().map(|&(line, _)| line) == Some(vm_line) {
+            self.source_map.pop();
+        }
+        self.source_map.push((vm_line, jack_line));
+    }
+
+    pub fn source_map(&self) -> &[(usize, usize)] {
+        &self.source_map
     }
 
-    fn write_segment(&mut self, segment: Segment) {
-        self.out_file.write(match segment {
-            Segment::ARG => b"argument ",
-            Segment::LOCAL => b"local ",
-            Segment::POINTER => b"pointer ",
-            Segment::STATIC => b"static ",
-            Segment::TEMP => b"temp ",
-            Segment::THIS => b"this ",
-            Segment::THAT => b"that ",
-            Segment::CONSTANT => b"constant ",
-        });
+    fn write_command(&mut self, command: VmCommand) {
+        self.lines += 1;
+        writeln!(self.out_file, "{}", command).expect("Unable to write to file.");
     }
 
     pub fn write_push(&mut self, segment: Segment, index: u16) {
-        self.out_file.write(b"push ");
-        self.write_segment(segment);
-        self.out_file.write_fmt(format_args!("{}\n", index));
+        self.write_command(VmCommand::Push(segment, index));
     }
 
     pub fn write_pop(&mut self, segment: Segment, index: u16) {
-        self.out_file.write(b"pop ");
-        self.write_segment(segment);
-        self.out_file.write_fmt(format_args!("{}\n", index));
+        self.write_command(VmCommand::Pop(segment, index));
     }
 
-    pub fn write_arithmetic(&mut self, command: Command) {
-        self.out_file.write(match command {
-            Command::ADD => b"add\n",
-            Command::SUB => b"sub\n",
-            Command::AND => b"and\n",
-            Command::OR => b"or\n",
-            Command::EQ => b"eq\n",
-            Command::GT => b"gt\n",
-            Command::LT => b"lt\n",
-            Command::NEG => b"neg\n",
-            Command::NOT => b"not\n",
-        });
+    pub fn write_arithmetic(&mut self, op: ArithOp) {
+        self.write_command(VmCommand::Arithmetic(op));
     }
 
     pub fn write_label(&mut self, label: String) {
-        self.out_file.write_fmt(format_args!("label {}\n", label));
+        self.write_command(VmCommand::Label(label));
     }
 
     pub fn write_goto(&mut self, label: String) {
-        self.out_file.write_fmt(format_args!("goto {}\n", label));
+        self.write_command(VmCommand::Goto(label));
     }
 
     pub fn write_if(&mut self, label: String) {
-        self.out_file.write_fmt(format_args!("if-goto {}\n", label));
+        self.write_command(VmCommand::IfGoto(label));
     }
 
-    pub fn write_call(&mut self, name: String, nArgs: u16) {
-        self.out_file.write_fmt(format_args!("call {} {}\n", name, nArgs));
+    pub fn write_call(&mut self, name: String, num_args: u16) {
+        self.write_command(VmCommand::Call(name, num_args));
     }
 
-    pub fn write_function(&mut self, name: String, nLocals: u16) {
-        self.out_file.write_fmt(format_args!("function {} {}\n", name, nLocals));
+    pub fn write_function(&mut self, name: String, num_locals: u16) {
+        self.write_command(VmCommand::Function(name, num_locals));
     }
 
     pub fn write_return(&mut self) {
-        self.out_file.write(b"return\n");
+        self.write_command(VmCommand::Return);
     }
 }